@@ -1,4 +1,4 @@
-pub const WORDS_FREQS: [(&str, f64); 1524] = [
+pub static WORDS_FREQS: [(&str, f64); 1524] = [
     ("borie", 0.000000),
     ("tosai", 0.000000),
     ("nonio", 0.000000),
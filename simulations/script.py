@@ -32,6 +32,8 @@ def main():
     # Extract the iteration counts and attempt columns
     iterations = df['max_iterations'].tolist()
     attempt_columns = [col for col in df.columns if col != 'max_iterations']
+    # The last column counts the unsolved games, e.g. '>6' with 6 attempts allowed
+    failure_columns = [col for col in attempt_columns if col.startswith('>') or col.endswith('+')]
     
     # Create positions for the bars
     r = np.arange(len(attempt_columns))
@@ -63,15 +65,14 @@ def main():
         # Modified weighted sum calculation to handle non-numeric column names
         weighted_sum = 0
         for col, count in zip(attempt_columns, values):
-            if col not in ['>6', '6+']:  # Skip the failure column
+            if col not in failure_columns:  # Skip the failure column
                 try:
                     weighted_sum += int(col) * count
                 except ValueError:
                     print(f"Warning: Could not convert column '{col}' to integer")
                     
         # Identify failure column and count
-        failure_col = '>6' if '>6' in attempt_columns else '6+'
-        failure_idx = attempt_columns.index(failure_col) if failure_col in attempt_columns else -1
+        failure_idx = attempt_columns.index(failure_columns[0]) if failure_columns else -1
         failures = values[failure_idx] if failure_idx >= 0 else 0
         
         successes = total_attempts - failures
@@ -82,7 +83,7 @@ def main():
         # Calculate variance - modified to handle non-numeric column names
         variance = 0
         for col, count in zip(attempt_columns, values):
-            if col not in ['>6', '6+']:  # Skip the failure column
+            if col not in failure_columns:  # Skip the failure column
                 try:
                     col_int = int(col)
                     variance += ((col_int - avg_attempts) ** 2) * count
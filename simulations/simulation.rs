@@ -1,20 +1,117 @@
-use std::sync::{Arc, Mutex};
-use std::fs::File;
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 // We need to add rand to Cargo.toml first
 // [dependencies]
 // rand = "0.8.5"
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use csv;
-include!("../src/solver.rs");
+use rand::{Rng, SeedableRng};
+use parole_bot::language::{Language, ITALIAN};
+use parole_bot::feedback::{feedback_pattern, Pattern, TileState};
+use parole_bot::parallel::parallel_map;
+use parole_bot::patterns::PatternTable;
+use parole_bot::solver::{FilterMode, GuessPool, Lookahead, Solver, DEFAULT_LOOKAHEAD_TOP_K};
+use parole_bot::strategy::{BlendStrategy, Normalization, Strategy};
+use parole_bot::words::WordLists;
+use parole_bot::logging::{self, LogConfig};
+use parole_bot::{GameConfig, SolverConfig};
 //include!("../assets/word_freqs_big.rs");
 
 const DEF_MAX_ITERATIONS: [i16; 3] = [50, 100, 200];
+const DEF_TOP_OPENERS: usize = 10;
+const DEF_CHECK_ROUNDS: usize = 1000;
+const DEF_CHECK_GUESSES: usize = 6;
+
+// Solver settings shared by every simulated game
+#[derive(Clone)]
+pub struct GameSettings {
+    pub filter_mode: FilterMode,
+    pub guess_pool: GuessPool,
+    pub hard_mode: bool,
+    pub lookahead: Lookahead,
+    pub strategy: Arc<dyn Strategy>,
+    pub normalization: Normalization,
+    // Game `i` picks its word with the seed `seed + i`, so runs are reproducible
+    pub seed: Option<u64>,
+    pub cross_check: bool,
+    // Words of the configured length, and the guesses allowed to find one
+    pub word_lists: WordLists,
+    pub language: &'static Language,
+    pub max_attempts: usize,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            filter_mode: FilterMode::Constraints,
+            guess_pool: GuessPool::Candidates,
+            hard_mode: false,
+            lookahead: Lookahead::Greedy,
+            strategy: Arc::new(BlendStrategy::default()),
+            normalization: Normalization::Max,
+            seed: None,
+            cross_check: false,
+            word_lists: WordLists::embedded(),
+            language: &ITALIAN,
+            max_attempts: GameConfig::default().max_attempts,
+        }
+    }
+}
 
 // Define a struct to hold parsed arguments
 pub struct Args {
     pub file_path: String,
     pub iterations: Vec<i16>,
+    // Cache files, the language ones (e.g. `patterns-it.cache`) if not given
+    pub pattern_cache: Option<String>,
+    pub opener_cache: Option<String>,
+    pub threads: usize,
+    pub config: SolverConfig,
+    pub log: LogConfig,
+    pub game: GameConfig,
+    pub settings: GameSettings,
+}
+
+// Arguments of the `openers` subcommand
+pub struct OpenersArgs {
+    pub top: usize,
+    pub pattern_cache: Option<String>,
+    pub opener_cache: Option<String>,
+    pub game: GameConfig,
+}
+
+impl Default for OpenersArgs {
+    fn default() -> Self {
+        Self {
+            top: DEF_TOP_OPENERS,
+            pattern_cache: None,
+            opener_cache: None,
+            game: GameConfig::default(),
+        }
+    }
+}
+
+// Arguments of the `check` subcommand
+pub struct CheckArgs {
+    pub rounds: usize,
+    pub guesses: usize,
+    pub seed: Option<u64>,
+    pub game: GameConfig,
+}
+
+impl Default for CheckArgs {
+    fn default() -> Self {
+        Self {
+            rounds: DEF_CHECK_ROUNDS,
+            guesses: DEF_CHECK_GUESSES,
+            seed: None,
+            game: GameConfig::default(),
+        }
+    }
 }
 
 impl Default for Args {
@@ -22,45 +119,339 @@ impl Default for Args {
         Self {
             file_path: "results.csv".to_string(),
             iterations: DEF_MAX_ITERATIONS.to_vec(),
+            pattern_cache: None,
+            opener_cache: None,
+            threads: 0,
+            config: SolverConfig::default(),
+            log: LogConfig::default(),
+            game: GameConfig::default(),
+            settings: GameSettings::default(),
         }
     }
 }
 
 fn main() {
+    let raw_args: Vec<String> = std::env::args().collect();
+
+    // `openers` subcommand: print the best opening words and exit
+    if raw_args.get(1).map(String::as_str) == Some("openers") {
+        print_openers(parse_openers_args(raw_args));
+        return;
+    }
+
+    // `check` subcommand: verify the feedback and the filters on random games and exit
+    if raw_args.get(1).map(String::as_str) == Some("check") {
+        run_checks(parse_check_args(raw_args));
+        return;
+    }
+
     // Parse command line arguments
-    let args = parse_args(std::env::args().collect());
+    let args = parse_args(raw_args);
+    if let Err(err) = logging::init(&args.log) {
+        eprintln!("Error: cannot open the log file {}: {}", args.log.path.display(), err);
+        std::process::exit(1);
+    }
     
     // Create and prepare the file
     {
         // First create/truncate the file and write the header
         let mut file = File::create(&args.file_path).unwrap();
-        writeln!(file, "max_iterations , 1 , 2 , 3 , 4 , 5 , 6 , >6").unwrap();
+        let classes = outcome_classes(args.settings.max_attempts);
+        writeln!(file, "max_iterations , {}", classes.join(" , ")).unwrap();
     }
     
-    // Create a mutex-protected file path for thread safety
-    let file_path = Arc::new(Mutex::new(args.file_path));
-    
-    // Use parsed iterations instead of hardcoded values
-    let max_iterations = args.iterations;
-    let mut handles = vec![];
-    
-    for max in max_iterations {
-        let file_path_clone = Arc::clone(&file_path);
-        let handle = std::thread::spawn(move || {
-            let classes = simulate_game(max);
-            
-            // Get a locked reference to the file path
-            let path = file_path_clone.lock().unwrap();
-            append_to_csv(&classes, max, &path);
-        });
-        handles.push(handle);
+    // Score every guess against every answer once, all the games share the table
+    let pattern_cache = cache_path(&args.pattern_cache, &args.game, "patterns");
+    let pattern_table = load_pattern_table(&pattern_cache, &args.settings.word_lists);
+
+    // Every game starts from the best opener
+    let opener_cache = cache_path(&args.opener_cache, &args.game, "openers");
+    let opener = best_opener(&pattern_table, &opener_cache, &args.settings.word_lists);
+    println!("Opening word: {}", opener);
+    println!("Strategy: {}", args.settings.strategy.name());
+
+    // The games of all the simulations are spread over the same workers
+    let results = simulate_games(&args.iterations, &args.settings, &pattern_table, &opener, args.threads);
+    for (&max, classes) in args.iterations.iter().zip(&results) {
+        append_to_csv(classes, max, &args.file_path, args.settings.max_attempts);
+    }
+
+    println!("All simulations finished. Results written to {}", args.file_path);
+}
+
+// The cache file given on the command line, or the default one of the language
+fn cache_path(path: &Option<String>, game: &GameConfig, kind: &str) -> PathBuf {
+    PathBuf::from(path.clone().unwrap_or_else(|| game.language.cache_file(kind)))
+}
+
+// Loads the pattern table for the word lists, building it if needed
+fn load_pattern_table(cache_path: &Path, lists: &WordLists) -> Arc<PatternTable> {
+    let solver = Solver::with_word_lists(lists.clone());
+    Arc::new(PatternTable::load_or_build(
+        cache_path,
+        solver.valid_guesses(),
+        solver.answers(),
+    ))
+}
+
+// Returns the opener with the highest entropy, using the cached ranking if possible
+fn best_opener(pattern_table: &Arc<PatternTable>, cache_path: &Path, lists: &WordLists) -> String {
+    let mut solver = Solver::with_word_lists(lists.clone());
+    solver.set_pattern_table(Arc::clone(pattern_table));
+    solver
+        .cached_openers(cache_path)
+        .into_iter()
+        .next()
+        .map(|(word, _)| word)
+        .unwrap_or_else(|| solver.answers()[0].clone())
+}
+
+pub fn print_openers(args: OpenersArgs) {
+    let lists = select_words(&args.game);
+    let pattern_table = load_pattern_table(&cache_path(&args.pattern_cache, &args.game, "patterns"), &lists);
+    let mut solver = Solver::with_word_lists(lists);
+    solver.set_pattern_table(pattern_table);
+
+    let openers = solver.cached_openers(&cache_path(&args.opener_cache, &args.game, "openers"));
+    println!("Top {} opening words:", args.top.min(openers.len()));
+    for (i, (word, entropy)) in openers.iter().take(args.top).enumerate() {
+        println!("{:>3}. {} ({:.4} bits)", i + 1, word, entropy);
+    }
+}
+
+pub fn parse_openers_args(args: Vec<String>) -> OpenersArgs {
+    let mut result = OpenersArgs::default();
+    let mut i = 2; // Skip program name and subcommand
+
+    while i < args.len() {
+        match args[i].as_str() {
+            "-n" | "--top" => {
+                if i + 1 < args.len() {
+                    match args[i + 1].parse::<usize>() {
+                        Ok(val) => result.top = val,
+                        Err(_) => {
+                            eprintln!("Error: Invalid number of openers '{}'", args[i + 1]);
+                            std::process::exit(1);
+                        }
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing number after -n/--top");
+                    std::process::exit(1);
+                }
+            },
+            "--pattern-cache" => {
+                if i + 1 < args.len() {
+                    result.pattern_cache = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing file path after --pattern-cache");
+                    std::process::exit(1);
+                }
+            },
+            "--opener-cache" => {
+                if i + 1 < args.len() {
+                    result.opener_cache = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing file path after --opener-cache");
+                    std::process::exit(1);
+                }
+            },
+            "--language" | "--word-length" | "--answers" | "--guesses" | "--freqs" => {
+                if i + 1 < args.len() {
+                    if let Err(e) = result.game.set_flag(&args[i], &args[i + 1]) {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing value after {}", args[i]);
+                    std::process::exit(1);
+                }
+            },
+            _ => {
+                eprintln!("Unknown argument: {}", args[i]);
+                eprintln!("Usage: {} openers [-n/--top COUNT] [--language italian|english] [--word-length N] [--answers FILE] [--guesses FILE] [--freqs FILE] [--pattern-cache FILE] [--opener-cache FILE]", args[0]);
+                std::process::exit(1);
+            }
+        }
     }
 
-    for handle in handles {
-        handle.join().unwrap();
+    result
+}
+
+// Plays random guesses against random answers and asserts that:
+// - an answer scored against itself is all greens,
+// - every pattern survives its code and its letter and emoji notations,
+// - the answer always survives filtering, whatever the guesses,
+// - both filtering strategies keep exactly the same candidates,
+// - undoing and redoing the whole history restores the same candidates,
+// - contradictions are only reported for corrupted histories, with a working fix.
+pub fn run_checks(args: CheckArgs) {
+    let lists = select_words(&args.game);
+    let answers = lists.answers.clone();
+    let guesses = lists.guesses.clone();
+    let mut rng = match args.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    for round in 0..args.rounds {
+        let answer = answers.choose(&mut rng).unwrap();
+        assert!(
+            feedback_pattern(answer, answer).is_solved(),
+            "'{}' scored against itself is not all greens",
+            answer
+        );
+
+        let mut solver = Solver::with_word_lists(lists.clone());
+        solver.set_language(args.game.language);
+        for _ in 0..args.guesses {
+            let guess = guesses.choose(&mut rng).unwrap();
+            let pattern = feedback_pattern(guess, answer);
+            assert_eq!(Pattern::from_code(pattern.code(), pattern.len()), pattern);
+            assert_eq!(pattern.to_string().parse::<Pattern>(), Ok(pattern.clone()));
+            assert_eq!(pattern.to_emoji().parse::<Pattern>(), Ok(pattern.clone()));
+            if let Err(err) = solver.record_feedback(guess, &pattern) {
+                panic!("Round {}: feedback for '{}' rejected: {}", round, answer, err);
+            }
+
+            let by_constraints = solver.filter_words_with_all_constraints(&answers);
+            let by_patterns = solver.filter_words_with_patterns(&answers);
+            assert!(
+                by_constraints.contains(answer),
+                "Round {}: answer '{}' filtered out by constraints after {:?}",
+                round, answer, solver.history()
+            );
+            assert!(
+                by_constraints == by_patterns,
+                "Round {}: filter mismatch for '{}' after {:?}: {} by constraints, {} by patterns",
+                round, answer, solver.history(), by_constraints.len(), by_patterns.len()
+            );
+        }
+
+        // Undoing and redoing every row gives back the same candidates
+        let candidates = solver.remaining_candidates();
+        let rows = solver.history().len();
+        for _ in 0..rows {
+            solver.undo();
+        }
+        assert_eq!(solver.remaining_candidates().len(), answers.len(), "Round {}: undo left rows behind", round);
+        for _ in 0..rows {
+            if let Err(err) = solver.redo() {
+                panic!("Round {}: redo failed for '{}': {}", round, answer, err);
+            }
+        }
+        assert_eq!(solver.remaining_candidates(), candidates, "Round {}: redo changed the candidates", round);
+
+        // The real history never conflicts, while a corrupted one is explained
+        // and the suggested flip fixes it
+        assert!(
+            solver.find_conflict(solver.history()).is_none(),
+            "Round {}: conflict reported for the history of '{}'",
+            round, answer
+        );
+        let mut history = solver.history().to_vec();
+        if let Some((_, pattern)) = history.last_mut() {
+            let position = rng.gen_range(0..pattern.len());
+            *pattern = flip_tile(pattern, position, rng.gen_range(1..3));
+        }
+        if let Some(fix) = solver.find_conflict(&history).and_then(|conflict| conflict.fix) {
+            let (_, pattern) = &mut history[fix.row];
+            let steps = (3 + fix.state.digit() - pattern.tiles()[fix.position].digit()) % 3;
+            *pattern = flip_tile(pattern, fix.position, steps);
+            assert!(
+                solver.find_conflict(&history).is_none(),
+                "Round {}: the suggested fix does not solve the conflict for '{}'",
+                round, answer
+            );
+        }
     }
 
-    println!("All threads finished. Results written to {}", file_path.lock().unwrap());
+    println!("All checks passed on {} random games", args.rounds);
+}
+
+// Moves the tile at `position` forward by `steps` states (R -> Y -> G -> R)
+fn flip_tile(pattern: &Pattern, position: usize, steps: u8) -> Pattern {
+    let mut tiles = pattern.tiles().to_vec();
+    let digit = (tiles[position].digit() + steps) % 3;
+    tiles[position] = TileState::from_digit(digit).unwrap();
+    Pattern::new(tiles).unwrap()
+}
+
+pub fn parse_check_args(args: Vec<String>) -> CheckArgs {
+    let mut result = CheckArgs::default();
+    let mut i = 2; // Skip program name and subcommand
+
+    while i < args.len() {
+        match args[i].as_str() {
+            "-n" | "--rounds" => {
+                if i + 1 < args.len() {
+                    match args[i + 1].parse::<usize>() {
+                        Ok(val) => result.rounds = val,
+                        Err(_) => {
+                            eprintln!("Error: Invalid number of rounds '{}'", args[i + 1]);
+                            std::process::exit(1);
+                        }
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing number after -n/--rounds");
+                    std::process::exit(1);
+                }
+            },
+            "-g" | "--guesses-per-round" => {
+                if i + 1 < args.len() {
+                    match args[i + 1].parse::<usize>() {
+                        Ok(val) => result.guesses = val,
+                        Err(_) => {
+                            eprintln!("Error: Invalid number of guesses '{}'", args[i + 1]);
+                            std::process::exit(1);
+                        }
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing number after -g/--guesses-per-round");
+                    std::process::exit(1);
+                }
+            },
+            "--seed" => {
+                if i + 1 < args.len() {
+                    match args[i + 1].parse::<u64>() {
+                        Ok(val) => result.seed = Some(val),
+                        Err(_) => {
+                            eprintln!("Error: Invalid seed '{}'", args[i + 1]);
+                            std::process::exit(1);
+                        }
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing seed after --seed");
+                    std::process::exit(1);
+                }
+            },
+            "--language" | "--word-length" | "--answers" | "--guesses" | "--freqs" => {
+                if i + 1 < args.len() {
+                    if let Err(e) = result.game.set_flag(&args[i], &args[i + 1]) {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing value after {}", args[i]);
+                    std::process::exit(1);
+                }
+            },
+            _ => {
+                eprintln!("Unknown argument: {}", args[i]);
+                eprintln!("Usage: {} check [-n/--rounds COUNT] [-g/--guesses-per-round COUNT] [--language italian|english] [--word-length N] [--answers FILE] [--guesses FILE] [--freqs FILE] [--seed SEED]", args[0]);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    result
 }
 
 pub fn parse_args(args: Vec<String>) -> Args {
@@ -103,102 +494,324 @@ pub fn parse_args(args: Vec<String>) -> Args {
                     std::process::exit(1);
                 }
             },
+            "-m" | "--filter-mode" => {
+                if i + 1 < args.len() {
+                    result.settings.filter_mode = match args[i + 1].as_str() {
+                        "constraints" => FilterMode::Constraints,
+                        "patterns" => FilterMode::PatternConsistency,
+                        other => {
+                            eprintln!("Error: Unknown filter mode '{}' (expected constraints or patterns)", other);
+                            std::process::exit(1);
+                        }
+                    };
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing mode after -m/--filter-mode");
+                    std::process::exit(1);
+                }
+            },
+            "-p" | "--guess-pool" => {
+                if i + 1 < args.len() {
+                    result.settings.guess_pool = match args[i + 1].as_str() {
+                        "candidates" => GuessPool::Candidates,
+                        "full" => GuessPool::FullDictionary,
+                        other => {
+                            eprintln!("Error: Unknown guess pool '{}' (expected candidates or full)", other);
+                            std::process::exit(1);
+                        }
+                    };
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing pool after -p/--guess-pool");
+                    std::process::exit(1);
+                }
+            },
+            "--pattern-cache" => {
+                if i + 1 < args.len() {
+                    result.pattern_cache = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing file path after --pattern-cache");
+                    std::process::exit(1);
+                }
+            },
+            "--opener-cache" => {
+                if i + 1 < args.len() {
+                    result.opener_cache = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing file path after --opener-cache");
+                    std::process::exit(1);
+                }
+            },
+            "-t" | "--threads" => {
+                if i + 1 < args.len() {
+                    match args[i + 1].parse::<usize>() {
+                        Ok(val) => result.threads = val,
+                        Err(_) => {
+                            eprintln!("Error: Invalid thread count '{}'", args[i + 1]);
+                            std::process::exit(1);
+                        }
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing thread count after -t/--threads");
+                    std::process::exit(1);
+                }
+            },
+            "-l" | "--lookahead" => {
+                if i + 1 < args.len() {
+                    result.settings.lookahead = match args[i + 1].as_str() {
+                        "greedy" => Lookahead::Greedy,
+                        "two-step" => Lookahead::TwoStep { top_k: DEFAULT_LOOKAHEAD_TOP_K },
+                        other => {
+                            eprintln!("Error: Unknown lookahead '{}' (expected greedy or two-step)", other);
+                            std::process::exit(1);
+                        }
+                    };
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing lookahead after -l/--lookahead");
+                    std::process::exit(1);
+                }
+            },
+            "--top-k" => {
+                if i + 1 < args.len() {
+                    match args[i + 1].parse::<usize>() {
+                        Ok(val) if val > 0 => result.settings.lookahead = Lookahead::TwoStep { top_k: val },
+                        _ => {
+                            eprintln!("Error: Invalid number of guesses '{}'", args[i + 1]);
+                            std::process::exit(1);
+                        }
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing number after --top-k");
+                    std::process::exit(1);
+                }
+            },
+            "--config" => {
+                if i + 1 < args.len() {
+                    if let Err(e) = result.config.load(Path::new(&args[i + 1])) {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing file path after --config");
+                    std::process::exit(1);
+                }
+            },
+            flag if SolverConfig::flag_key(flag).is_some() => {
+                let key = SolverConfig::flag_key(flag).unwrap();
+                if i + 1 < args.len() {
+                    if let Err(e) = result.config.set(key, &args[i + 1]) {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing value after {}", flag);
+                    std::process::exit(1);
+                }
+            },
+            "--seed" => {
+                if i + 1 < args.len() {
+                    match args[i + 1].parse::<u64>() {
+                        Ok(val) => result.settings.seed = Some(val),
+                        Err(_) => {
+                            eprintln!("Error: Invalid seed '{}'", args[i + 1]);
+                            std::process::exit(1);
+                        }
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing seed after --seed");
+                    std::process::exit(1);
+                }
+            },
+            "--log-level" | "--log-format" | "--log-file" => {
+                if i + 1 < args.len() {
+                    if let Err(e) = result.log.set_flag(&args[i], &args[i + 1]) {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing value after {}", args[i]);
+                    std::process::exit(1);
+                }
+            },
+            "--language" | "--word-length" | "--max-attempts" | "--answers" | "--guesses" | "--freqs" => {
+                if i + 1 < args.len() {
+                    if let Err(e) = result.game.set_flag(&args[i], &args[i + 1]) {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(1);
+                    }
+                    i += 2;
+                } else {
+                    eprintln!("Error: Missing value after {}", args[i]);
+                    std::process::exit(1);
+                }
+            },
+            "--hard-mode" => {
+                result.settings.hard_mode = true;
+                i += 1;
+            },
+            "-c" | "--cross-check" => {
+                result.settings.cross_check = true;
+                i += 1;
+            },
             _ => {
                 eprintln!("Unknown argument: {}", args[i]);
-                eprintln!("Usage: {} -f/--file FILE -i/--iterations COUNT [COUNT...]", args[0]);
+                eprintln!("Usage: {} [openers|check] -f/--file FILE -i/--iterations COUNT [COUNT...] [-m/--filter-mode constraints|patterns] [-p/--guess-pool candidates|full] [--hard-mode] [-l/--lookahead greedy|two-step] [--top-k COUNT] [--config FILE] [-s/--strategy NAME] [--entropy-weight W] [--frequency-weight W] [--normalization max|min-max|none] [--bits-per-guess B] [--language italian|english] [--word-length N] [--max-attempts N] [--answers FILE] [--guesses FILE] [--freqs FILE] [--seed SEED] [-c/--cross-check] [--pattern-cache FILE] [--opener-cache FILE] [-t/--threads COUNT] [--log-level LEVEL] [--log-format text|json] [--log-file FILE]", args[0]);
                 std::process::exit(1);
             }
         }
     }
+
+
+    // Build the strategy once all the settings are known
+    result.settings.strategy = result.config.build_strategy();
+    result.settings.normalization = result.config.normalization;
+    result.settings.word_lists = select_words(&result.game);
+    result.settings.language = result.game.language;
+    result.settings.max_attempts = result.game.max_attempts;
     
     result
 }
 
-pub fn get_colorcode(chosen_word: &str, guess: &str) -> String {
-    let mut colorcode = String::new();
-    for (i, c) in guess.chars().enumerate() {
-        //if the character is not in the word
-        if !chosen_word.contains(c){
-            colorcode.push('R');
-        }else{
-            //if the character is in the word but not in the right position
-            if chosen_word.chars().nth(i).unwrap() != c{
-                colorcode.push('Y');
-            }else{
-                //if the character is in the word and in the right position
-                colorcode.push('G');
-            }
+// The word lists of the configured length, exits if they cannot be read or are empty
+fn select_words(game: &GameConfig) -> WordLists {
+    match game.word_lists() {
+        Ok(lists) => lists,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
         }
     }
-    colorcode
 }
 
-pub fn simulate_game(max_iterations: i16) -> HashMap<String, i16> {
-    let mut classes: HashMap<String, i16> = HashMap::from([
-        ("1".to_string(), 0),
-        ("2".to_string(), 0),
-        ("3".to_string(), 0),
-        ("4".to_string(), 0),
-        ("5".to_string(), 0),
-        ("6".to_string(), 0),
-        (">6".to_string(), 0),
-    ]);
-        
-    println!("Max iterations: {}", max_iterations);
-    
-    let mut iteration = 0;
-    while iteration < max_iterations {  // Changed <= to <
-        // Create a fresh solver for each game
-        let mut solver = Solver::new();
-        let words: Vec<String> = WORDS.iter().map(|&s| s.to_string()).collect();
-        
-        let chosen_word = words.choose(&mut rand::thread_rng()).unwrap();
-        let mut attempt = 1;
-        
-        // Use the solver's best opener for the first guess
-        let mut guess = "tares".to_string();  // Starting word
-        
-        while attempt <= 6 {
-            // println!("Iteration: {}, Attempt: {}", iteration + 1, attempt);
-            // println!("Chosen word: {}, guess: {}", chosen_word, guess);
-
-            if guess == *chosen_word {
-                //println!("Found the word: {}", chosen_word);
-                // Update the counter for this attempt number
-                let class = classes.entry(attempt.to_string()).or_insert(0);
-                *class += 1;
-                break;
-            }
+// Outcome classes of a game: the number of attempts it took, or ">N" if it was
+// not solved within the N attempts allowed
+fn outcome_classes(max_attempts: usize) -> Vec<String> {
+    (1..=max_attempts)
+        .map(|attempt| attempt.to_string())
+        .chain(std::iter::once(format!(">{}", max_attempts)))
+        .collect()
+}
 
-            let colorcode = get_colorcode(chosen_word, &guess);
-            //println!("Colorcode: {}", colorcode);
+// Plays the games of every simulation, `iterations[i]` games for the i-th one,
+// and counts the outcomes of each simulation separately
+pub fn simulate_games(iterations: &[i16], settings: &GameSettings, pattern_table: &Arc<PatternTable>, opener: &str, threads: usize) -> Vec<HashMap<String, i16>> {
+    let mut results: Vec<HashMap<String, i16>> = iterations
+        .iter()
+        .map(|_| outcome_classes(settings.max_attempts).into_iter().map(|class| (class, 0)).collect())
+        .collect();
 
-            let res = solver.get_next_possible_words(&guess, &colorcode);
+    let counts: Vec<String> = iterations.iter().map(|count| count.to_string()).collect();
+    println!("Max iterations: {}", counts.join(", "));
 
-            if res.is_empty() {
-                println!("No possible words found");
-                break;
-            }
+    // Every (simulation, game) pair is a job, so a single set of workers plays
+    // all of them and the faster simulations do not wait for the slower ones
+    let games: Vec<(usize, i16)> = iterations
+        .iter()
+        .enumerate()
+        .flat_map(|(simulation, &max_iterations)| (0..max_iterations).map(move |game| (simulation, game)))
+        .collect();
+    let outcomes = parallel_map(&games, threads, |&(_, game)| match settings.seed {
+        Some(seed) => {
+            let mut rng = StdRng::seed_from_u64(seed.wrapping_add(game as u64));
+            play_game(settings, pattern_table, opener, &mut rng)
+        }
+        None => play_game(settings, pattern_table, opener, &mut rand::thread_rng()),
+    });
 
-            // Get the first word in res
-            guess = res[0].clone();
-            //println!("Next guess: {}", guess);
+    for (&(simulation, _), outcome) in games.iter().zip(outcomes) {
+        if let Some(class) = outcome {
+            *results[simulation].entry(class).or_insert(0) += 1;
+        }
+    }
 
-            attempt += 1;
+    results
+}
+
+// Plays a single game against a random word, returning the number of attempts
+// (or ">N" past the last attempt) it took, or None if the game had to be aborted.
+fn play_game<R: Rng>(settings: &GameSettings, pattern_table: &Arc<PatternTable>, opener: &str, rng: &mut R) -> Option<String> {
+    // Create a fresh solver for each game; the games already run in parallel,
+    // so the solver scores its guesses on the game's own thread
+    let mut solver = Solver::with_word_lists(settings.word_lists.clone());
+    solver.set_pattern_table(Arc::clone(pattern_table));
+    solver.set_language(settings.language);
+    solver.set_filter_mode(settings.filter_mode);
+    solver.guess_pool = settings.guess_pool;
+    solver.hard_mode = settings.hard_mode;
+    solver.lookahead = settings.lookahead;
+    solver.strategy = Arc::clone(&settings.strategy);
+    solver.normalization = settings.normalization;
+    solver.threads = 1;
+    let words = &settings.word_lists.answers;
+    
+    let chosen_word = words.choose(rng).unwrap();
+    let mut attempt = 1;
+    
+    // Use the solver's best opener for the first guess
+    let mut guess = opener.to_string();
+    
+    while attempt <= settings.max_attempts {
+        // println!("Attempt: {}", attempt);
+        // println!("Chosen word: {}, guess: {}", chosen_word, guess);
+
+        if guess == *chosen_word {
+            //println!("Found the word: {}", chosen_word);
+            return Some(attempt.to_string());
         }
 
-        // Handle case where word wasn't found in 6 attempts
-        if attempt > 6 {
-            //println!("Word not found in 6 attempts");
-            let class = classes.entry(">6".to_string()).or_insert(0);
-            *class += 1;
+        let colorcode = feedback_pattern(&guess, chosen_word);
+        //println!("Colorcode: {}", colorcode);
+
+        let res = solver.get_next_possible_words(&guess, &colorcode);
+
+        // The answer must always survive filtering
+        assert!(
+            solver.remaining_candidates().contains(chosen_word),
+            "Target '{}' filtered out after guess '{}' ({})",
+            chosen_word, guess, colorcode
+        );
+
+        // Both filtering strategies must agree on the remaining candidates
+        if settings.cross_check {
+            let by_constraints = solver.filter_words_with_all_constraints(words);
+            let by_patterns = solver.filter_words_with_patterns(words);
+            assert!(
+                by_constraints == by_patterns,
+                "Filter mismatch for '{}' after {:?}: {} by constraints, {} by patterns",
+                chosen_word, solver.history(), by_constraints.len(), by_patterns.len()
+            );
         }
-        
-        // Don't forget to increment iteration!
-        iteration += 1;
+
+        let res = match res {
+            Ok(res) if !res.is_empty() => res,
+            Ok(_) => {
+                println!("No possible words found");
+                return None;
+            }
+            Err(err) => {
+                println!("Game for '{}' aborted: {}", chosen_word, err);
+                return None;
+            }
+        };
+
+        // Get the first word in res
+        guess = res[0].word.clone();
+        //println!("Next guess: {}", guess);
+
+        attempt += 1;
     }
 
-    classes
+    // The word wasn't found in the attempts allowed
+    Some(format!(">{}", settings.max_attempts))
 }
 
 pub fn words_intersection(word_freqs: [(&str, f64); 41730], words: Vec<String>) -> HashMap<&str, f64> {
@@ -219,10 +832,9 @@ pub fn words_intersection(word_freqs: [(&str, f64); 41730], words: Vec<String>)
 }
 
 // New function to append to the CSV file
-pub fn append_to_csv(classes: &HashMap<String, i16>, max_iterations: i16, file_path: &str) -> String {
+pub fn append_to_csv(classes: &HashMap<String, i16>, max_iterations: i16, file_path: &str, max_attempts: usize) -> String {
     // Open file in append mode
     let file = OpenOptions::new()
-        .write(true)
         .append(true)
         .open(file_path)
         .unwrap();
@@ -232,12 +844,12 @@ pub fn append_to_csv(classes: &HashMap<String, i16>, max_iterations: i16, file_p
         .from_writer(file);
     
     // Create required keys list to ensure consistent CSV structure
-    let required_keys = vec!["1", "2", "3", "4", "5", "6", ">6"];
+    let required_keys = outcome_classes(max_attempts);
     let mut record = vec![max_iterations.to_string()];
 
     // Process only the required keys in specific order
     for key in &required_keys {
-        let count = classes.get(&key.to_string()).unwrap_or(&0);
+        let count = classes.get(key).unwrap_or(&0);
         record.push(count.to_string());
     }
     
@@ -1,14 +1,34 @@
-use ratatui::{style::Color, widgets::{ListState, TableState}};
-use std::sync::mpsc::{self, Receiver};
-
-
-use crate::solver::Solver;
+use ratatui::{layout::{Position, Rect}, style::Color, widgets::{ListState, TableState}};
+use std::{path::{Path, PathBuf}, sync::{mpsc::{self, Receiver}, Arc}, time::{Duration, Instant}};
+
+
+use parole_bot::{
+    config::{GameConfig, SolverConfig},
+    conflict::Conflict,
+    error::SolverError,
+    feedback::{Pattern, PatternError, TileState},
+    patterns::PatternTable,
+    solver::{GuessPool, Lookahead, ScoredGuess, Solver, DEFAULT_LOOKAHEAD_TOP_K},
+    strategy::{Normalization, STRATEGY_NAMES},
+    words::WordLists,
+};
+
+// Number of opening words suggested before the first guess
+const TOP_OPENERS: usize = 100;
+// Corrections offered for a word that is not in the dictionary, and how many
+// edits away from it they can be
+const DID_YOU_MEAN_COUNT: usize = 3;
+const MAX_TYPO_DISTANCE: usize = 2;
+// How long a rejected key is shown in the grid
+const REJECTED_KEY_DURATION: Duration = Duration::from_millis(800);
 
 #[derive(Copy, Clone, PartialEq)]
 pub enum CurrentScreen {
     Main,
     EditingTileChar,
     EditingTileColor,
+    Settings,
+    Candidates,
     Exiting,
 }
 
@@ -37,6 +57,81 @@ impl TileColor {
             TileColor::Normal => Color::Gray,
         }
     }
+
+    /// Feedback carried by the tile, `None` if it has not been colored yet.
+    pub fn to_state(&self) -> Option<TileState> {
+        match self {
+            TileColor::CorrectPlace => Some(TileState::Correct),
+            TileColor::Absent => Some(TileState::Absent),
+            TileColor::WrongPlace => Some(TileState::Present),
+            TileColor::Normal => None,
+        }
+    }
+
+    pub fn from_state(state: TileState) -> Self {
+        match state {
+            TileState::Correct => TileColor::CorrectPlace,
+            TileState::Absent => TileColor::Absent,
+            TileState::Present => TileColor::WrongPlace,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TileColor::CorrectPlace => "green",
+            TileColor::Absent => "red",
+            TileColor::WrongPlace => "yellow",
+            TileColor::Normal => "uncolored",
+        }
+    }
+}
+
+/// Column the table of suggestions is sorted by.
+#[derive(Copy, Clone, PartialEq)]
+pub enum SuggestionSort {
+    Rank,
+    Entropy,
+    Frequency,
+    WorstCase,
+    ExpectedSize,
+}
+
+impl SuggestionSort {
+    pub const ALL: [SuggestionSort; 5] = [
+        SuggestionSort::Rank,
+        SuggestionSort::Entropy,
+        SuggestionSort::Frequency,
+        SuggestionSort::WorstCase,
+        SuggestionSort::ExpectedSize,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SuggestionSort::Rank => "rank",
+            SuggestionSort::Entropy => "entropy",
+            SuggestionSort::Frequency => "frequency",
+            SuggestionSort::WorstCase => "worst case",
+            SuggestionSort::ExpectedSize => "expected left",
+        }
+    }
+}
+
+/// Order of the remaining candidates in the candidates browser.
+#[derive(Copy, Clone, PartialEq)]
+pub enum CandidateSort {
+    /// Most likely answers first. Only offered when the candidates are not all
+    /// equally likely, otherwise it would be the alphabetical order.
+    Chance,
+    Alphabetical,
+}
+
+impl CandidateSort {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CandidateSort::Chance => "chance",
+            CandidateSort::Alphabetical => "word",
+        }
+    }
 }
 
 #[derive(PartialEq)]
@@ -52,23 +147,99 @@ pub struct TilesGrid {
 }
 
 pub struct App {
-    pub calculating_receiver: Option<Receiver<Vec<String>>>,
+    pub calculating_receiver: Option<Receiver<Result<Vec<ScoredGuess>, SolverError>>>,
+    pub pattern_table_receiver: Option<Receiver<Arc<PatternTable>>>,
     pub is_solving:bool,
     pub tiles_grid: TilesGrid,
     pub selected_tile: (usize, usize),
     pub current_screen: CurrentScreen,
     pub currently_editing: Option<CurrentlyEditing>,
     pub table_state: TableState,
-    pub next_possible_words: Vec<String>,
+    // Ranked suggestions, best first, and how the table of suggestions is sorted
+    pub next_possible_words: Vec<ScoredGuess>,
+    pub suggestion_sort: SuggestionSort,
     pub list_state: ListState,
+    // Where the top suggestions and the rows of the table of suggestions were
+    // last drawn, to find the suggestion under a mouse click
+    pub top_suggestions_area: Rect,
+    pub suggestions_area: Rect,
+    // Candidates browser: search typed so far, order, page shown and the number
+    // of candidates that fit in a page, set when it is drawn
+    pub candidate_search: String,
+    pub candidate_sort: CandidateSort,
+    pub candidate_page: usize,
+    pub candidate_page_size: usize,
     pub solver: Solver,
+    pub status_message: Option<String>,
+    // Grid row of each entry of the solver history, in the same order
+    pub submitted_rows: Vec<usize>,
+    // Grid rows removed by undo, the last one is restored first by redo
+    pub undone_rows: Vec<usize>,
+    // Tiles (row, col) of the grid that contradict each other, highlighted until fixed
+    pub conflict_tiles: Vec<(usize, usize)>,
+    // Last key that is not a letter of the language, and when it was typed
+    pub rejected_key: Option<(char, Instant)>,
+    // Dictionary words close to the last row rejected as unknown, nearest first
+    pub did_you_mean: Vec<String>,
+    pub config: SolverConfig,
+    pub config_path: PathBuf,
+    pub selected_setting: usize,
+    // Language, word lists and shape of the game being played
+    pub game: GameConfig,
 }
 
 impl App {
-    pub fn new() -> App {
-        let tiles_grid = TilesGrid {
-            tiles: (0..6).map(|row| {
-                (0..5).map(|col| {
+    /// Creates the app playing with the given word lists, loaded from the
+    /// settings of `game`.
+    pub fn new(config: SolverConfig, config_path: PathBuf, game: GameConfig, lists: WordLists) -> App {
+        let mut solver = Solver::with_word_lists(lists);
+        solver.set_language(game.language);
+        solver.strategy = config.build_strategy();
+        solver.normalization = config.normalization;
+
+        let mut table_state = TableState::default();
+        table_state.select(Some(0));
+
+        let mut app = App {
+            calculating_receiver: None,
+            pattern_table_receiver: None,
+            is_solving: true,
+            tiles_grid: TilesGrid { tiles: Vec::new() },
+            selected_tile: (0, 0),
+            current_screen: CurrentScreen::Main,
+            currently_editing: None,
+            table_state,
+            next_possible_words: Vec::new(),
+            suggestion_sort: SuggestionSort::Rank,
+            list_state: ListState::default(),
+            top_suggestions_area: Rect::default(),
+            suggestions_area: Rect::default(),
+            candidate_search: String::new(),
+            candidate_sort: CandidateSort::Chance,
+            candidate_page: 0,
+            candidate_page_size: 1,
+            solver,
+            status_message: None,
+            submitted_rows: Vec::new(),
+            undone_rows: Vec::new(),
+            conflict_tiles: Vec::new(),
+            rejected_key: None,
+            did_you_mean: Vec::new(),
+            config,
+            config_path,
+            selected_setting: 0,
+            game,
+        };
+        app.new_game();
+        app
+    }
+
+    // Starts over with an empty grid, one row per allowed attempt and one column
+    // per letter, and prepares the first suggestions for the solver's word lists
+    fn new_game(&mut self) {
+        self.tiles_grid = TilesGrid {
+            tiles: (0..self.game.max_attempts).map(|row| {
+                (0..self.solver.word_length).map(|col| {
                     Tile {
                         character: ' ',
                         color: TileColor::Normal,
@@ -78,30 +249,274 @@ impl App {
                 }).collect()
             }).collect(),
         };
-        
-        let mut table_state = TableState::default();
-        table_state.select(Some(0));
+        self.selected_tile = (0, 0);
+        self.submitted_rows.clear();
+        self.undone_rows.clear();
+        self.conflict_tiles.clear();
+        self.did_you_mean.clear();
+        self.next_possible_words.clear();
+        self.list_state.select(Some(0));
+
+        // Load or build the pattern table in the background, the solver works without it meanwhile.
+        // Then use it to find the best opening words, which are the first suggestions.
+        let (table_tx, table_rx) = mpsc::channel();
+        let (openers_tx, openers_rx) = mpsc::channel();
+        let mut opener_solver = self.solver.clone();
+        let pattern_cache = PathBuf::from(self.game.language.cache_file("patterns"));
+        let opener_cache = PathBuf::from(self.game.language.cache_file("openers"));
+        std::thread::spawn(move || {
+            let table = Arc::new(PatternTable::load_or_build(
+                &pattern_cache,
+                opener_solver.valid_guesses(),
+                opener_solver.answers(),
+            ));
+            let _ = table_tx.send(Arc::clone(&table));
+
+            opener_solver.set_pattern_table(table);
+            let _ = openers_tx.send(Ok(top_openers(&opener_solver, &opener_cache)));
+        });
+        self.pattern_table_receiver = Some(table_rx);
+        self.calculating_receiver = Some(openers_rx);
+        self.is_solving = true;
+    }
 
-        let mut list_state = ListState::default();
-        list_state.select(Some(0));
+    /// Switches to the next bundled language and starts a new game with its
+    /// word lists. Word lists loaded from files are dropped.
+    pub fn next_language(&mut self) {
+        let mut game = self.game.clone();
+        game.language = game.language.next();
+        game.answers = None;
+        game.guesses = None;
+        game.frequencies = None;
+
+        let lists = match game.word_lists() {
+            Ok(lists) => lists,
+            Err(err) => {
+                self.status_message = Some(format!("Cannot play in {}: {}", game.language.name, err));
+                return;
+            }
+        };
+        // Keep the settings of the current solver
+        let mut solver = Solver::with_word_lists(lists);
+        solver.set_language(game.language);
+        solver.strategy = Arc::clone(&self.solver.strategy);
+        solver.normalization = self.solver.normalization;
+        solver.guess_pool = self.solver.guess_pool;
+        solver.hard_mode = self.solver.hard_mode;
+        solver.lookahead = self.solver.lookahead;
+        solver.set_filter_mode(self.solver.filter_mode());
+        solver.threads = self.solver.threads;
+
+        self.status_message = Some(format!("Playing in {}", game.language.name));
+        self.solver = solver;
+        self.game = game;
+        self.new_game();
+    }
 
-        App {
-            calculating_receiver: None,
-            is_solving: false,
-            tiles_grid,
-            selected_tile: (0, 0),
-            current_screen: CurrentScreen::Main,
-            currently_editing: None,
-            table_state,
-            next_possible_words: Vec::new(),
-            list_state,
-            solver: Solver::new(),
+    /// Usual first guess of the language, if it can be played with the
+    /// current word lists. Shown until the openers are ranked.
+    pub fn default_opener(&self) -> Option<&'static str> {
+        let opener = self.game.language.opener;
+        self.solver.valid_guesses().iter().any(|guess| guess == opener).then_some(opener)
+    }
+
+    /// Suggestions with their rank (0 for the best one), in the order of
+    /// `suggestion_sort`: the best values first, i.e. the highest entropy and
+    /// frequency and the fewest candidates left. Ties keep the rank order.
+    pub fn sorted_suggestions(&self) -> Vec<(usize, &ScoredGuess)> {
+        let mut sorted: Vec<(usize, &ScoredGuess)> = self.next_possible_words.iter().enumerate().collect();
+        let descending = |a: f64, b: f64| b.partial_cmp(&a).unwrap_or(std::cmp::Ordering::Equal);
+        match self.suggestion_sort {
+            SuggestionSort::Rank => {}
+            SuggestionSort::Entropy => sorted.sort_by(|(_, a), (_, b)| descending(a.entropy, b.entropy)),
+            SuggestionSort::Frequency => {
+                sorted.sort_by(|(_, a), (_, b)| descending(a.normalized_frequency, b.normalized_frequency))
+            }
+            SuggestionSort::WorstCase => sorted.sort_by_key(|(_, guess)| guess.largest_bucket),
+            SuggestionSort::ExpectedSize => {
+                sorted.sort_by(|(_, a), (_, b)| descending(b.expected_size, a.expected_size))
+            }
+        }
+        sorted
+    }
+
+    /// Sorts the table of suggestions by the next column, back to the top.
+    pub fn next_suggestion_sort(&mut self) {
+        let current = SuggestionSort::ALL.iter().position(|&sort| sort == self.suggestion_sort).unwrap_or(0);
+        self.suggestion_sort = SuggestionSort::ALL[cycle(current, SuggestionSort::ALL.len(), 1)];
+        self.list_state.select(Some(0));
+    }
+
+    /// Moves the selection in the table of suggestions by `offset` rows,
+    /// stopping at the first and the last one.
+    pub fn scroll_suggestions(&mut self, offset: isize) {
+        let last = self.next_possible_words.len().saturating_sub(1);
+        let selected = self.list_state.selected().unwrap_or(0);
+        self.list_state.select(Some(selected.saturating_add_signed(offset).min(last)));
+    }
+
+    /// Fills the next row of the grid (the one after the last submitted row)
+    /// with the suggestion of the given rank, and switches to editing its colors.
+    pub fn pick_suggestion(&mut self, rank: usize) {
+        // The suggestions are stale until the calculation ends
+        if self.is_solving {
+            return;
+        }
+        let Some(word) = self.next_possible_words.get(rank).map(|guess| guess.word.clone()) else {
+            return;
+        };
+        let row = self.submitted_rows.iter().max().map_or(0, |&last| last + 1);
+        let Some(tiles) = self.tiles_grid.tiles.get_mut(row) else {
+            self.status_message = Some(format!("No rows left for {}", word.to_uppercase()));
+            return;
+        };
+        for (tile, letter) in tiles.iter_mut().zip(word.chars()) {
+            tile.character = letter.to_uppercase().next().unwrap_or(letter);
+            tile.color = TileColor::Normal;
+        }
+        self.conflict_tiles.retain(|&(conflict_row, _)| conflict_row != row);
+        self.did_you_mean.clear();
+        self.update_selected_tile(row, 0);
+        self.current_screen = CurrentScreen::EditingTileColor;
+        self.currently_editing = Some(CurrentlyEditing::TileColor);
+        self.status_message = Some(format!("Row {}: {}, now enter its colors", row + 1, word.to_uppercase()));
+    }
+
+    /// Picks the suggestion selected in the table, see `pick_suggestion`.
+    pub fn pick_selected_suggestion(&mut self) {
+        let selected = self.list_state.selected().unwrap_or(0);
+        if let Some(&(rank, _)) = self.sorted_suggestions().get(selected) {
+            self.pick_suggestion(rank);
+        }
+    }
+
+    /// Picks the suggestion under a mouse click, in the top suggestions or in
+    /// the table, see `pick_suggestion`. Clicks elsewhere are ignored.
+    pub fn click(&mut self, column: u16, row: u16) {
+        let position = Position::new(column, row);
+        if self.top_suggestions_area.contains(position) {
+            // Below the top border, one suggestion per line
+            if let Some(line) = row.checked_sub(self.top_suggestions_area.y + 1) {
+                if (line as usize) < 3 {
+                    self.pick_suggestion(line as usize);
+                }
+            }
+        } else if self.suggestions_area.contains(position) {
+            let index = self.list_state.offset() + (row - self.suggestions_area.y) as usize;
+            if index < self.next_possible_words.len() {
+                self.list_state.select(Some(index));
+                self.pick_selected_suggestion();
+            }
+        }
+    }
+
+    /// Remaining candidates containing the search, with the probability of
+    /// each one being the answer, in the order of `shown_candidate_sort`.
+    pub fn browsed_candidates(&self) -> Vec<(String, f64)> {
+        let mut candidates: Vec<(String, f64)> = self
+            .solver
+            .candidate_probabilities()
+            .into_iter()
+            .filter(|(word, _)| word.contains(&self.candidate_search))
+            .collect();
+        match self.shown_candidate_sort() {
+            CandidateSort::Chance => candidates.sort_by(|a, b| {
+                b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal).then_with(|| a.0.cmp(&b.0))
+            }),
+            CandidateSort::Alphabetical => candidates.sort_by(|a, b| a.0.cmp(&b.0)),
+        }
+        candidates
+    }
+
+    /// Adds a key to the search of the candidates browser, normalized like the
+    /// letters typed in the grid. Keys that are not letters are ignored.
+    pub fn search_char(&mut self, c: char) {
+        if let Ok(Some(letter)) = self.game.language.normalize_char(c) {
+            self.candidate_search.push(letter);
+            self.candidate_page = 0;
         }
     }
 
+    /// Removes the last letter of the search of the candidates browser.
+    pub fn search_backspace(&mut self) {
+        self.candidate_search.pop();
+        self.candidate_page = 0;
+    }
+
+    /// Whether some remaining candidates are more likely than others, i.e.
+    /// whether sorting them by chance differs from sorting them by word.
+    pub fn candidate_chances_differ(&self) -> bool {
+        let probabilities = self.solver.candidate_probabilities();
+        probabilities.windows(2).any(|pair| pair[0].1 != pair[1].1)
+    }
+
+    /// Order of the candidates browser: `candidate_sort`, falling back to the
+    /// alphabetical order when every candidate is equally likely.
+    pub fn shown_candidate_sort(&self) -> CandidateSort {
+        if self.candidate_chances_differ() {
+            self.candidate_sort
+        } else {
+            CandidateSort::Alphabetical
+        }
+    }
+
+    /// Switches the order of the candidates browser, unless every candidate is
+    /// equally likely and the alphabetical order is the only one.
+    pub fn toggle_candidate_sort(&mut self) {
+        if !self.candidate_chances_differ() {
+            return;
+        }
+        self.candidate_sort = match self.candidate_sort {
+            CandidateSort::Chance => CandidateSort::Alphabetical,
+            CandidateSort::Alphabetical => CandidateSort::Chance,
+        };
+        self.candidate_page = 0;
+    }
+
+    /// Number of pages of the candidates browser, at least one.
+    pub fn candidate_page_count(&self) -> usize {
+        self.browsed_candidates().len().div_ceil(self.candidate_page_size.max(1)).max(1)
+    }
+
+    /// Moves the candidates browser by `offset` pages, stopping at the first
+    /// and the last one.
+    pub fn turn_candidate_page(&mut self, offset: isize) {
+        let last = self.candidate_page_count() - 1;
+        self.candidate_page = self.candidate_page.saturating_add_signed(offset).min(last);
+    }
+
+    /// Number of letters in a row of the grid.
+    pub fn word_length(&self) -> usize {
+        self.solver.word_length
+    }
+
+    /// Types a key in the selected tile: accented letters are folded to the
+    /// letters of the word lists, apostrophes are ignored and keys that are not
+    /// letters of the language are rejected.
+    pub fn type_char(&mut self, c: char) {
+        match self.game.language.normalize_char(c) {
+            Ok(Some(letter)) => {
+                self.rejected_key = None;
+                for upper in letter.to_uppercase() {
+                    self.insert_char(upper);
+                }
+            }
+            Ok(None) => {}
+            Err(c) => self.rejected_key = Some((c, Instant::now())),
+        }
+    }
+
+    /// Key rejected by `type_char` a moment ago, if any.
+    pub fn recently_rejected_key(&self) -> Option<char> {
+        self.rejected_key
+            .filter(|(_, at)| at.elapsed() < REJECTED_KEY_DURATION)
+            .map(|(c, _)| c)
+    }
+
     pub fn insert_char(&mut self, c: char) {
         // Get the coordinates of the selected cell
         let (row, col) = self.selected_tile;
+        let width = self.word_length();
 
         // Insert the character in the selected cell
         if let Some(tile) = self.tiles_grid.tiles.get_mut(row).and_then(|r| r.get_mut(col)) {
@@ -112,10 +527,10 @@ impl App {
         }
 
         // Move to the next cell to the right, if not the last column
-        if col < 4 {
+        if col + 1 < width {
             self.update_selected_tile(row, col + 1);
             // Update column state in TableState
-            self.table_state.select(Some(row * 5 + col + 1));
+            self.table_state.select(Some(row * width + col + 1));
         } 
     }
     
@@ -140,6 +555,7 @@ impl App {
     pub fn remove_char(&mut self) {
         // Get the coordinates of the selected cell
         let (row, col) = self.selected_tile;
+        let width = self.word_length();
         
         // Remove the character from the selected cell
         if let Some(tile) = self.tiles_grid.tiles.get_mut(row).and_then(|r| r.get_mut(col)) {
@@ -150,27 +566,28 @@ impl App {
         if col > 0 {
             self.update_selected_tile(row, col - 1);
            // Update the tablestate
-           self.table_state.select(Some(row * 5 + col - 1));
+           self.table_state.select(Some(row * width + col - 1));
         }
         
         // Move to the previous row if this is the first column
         //else if row > 0{
-        //    self.update_selected_tile(row - 1, 4);
+        //    self.update_selected_tile(row - 1, width - 1);
         //    // Update row state in TableState
-        //    self.table_state.select(Some((row - 1) * 5 + 4));
+        //    self.table_state.select(Some((row - 1) * width + width - 1));
         //}
         
     }
     
     pub fn go_next_row(&mut self) {
         let (row, col) = self.selected_tile;
+        let width = self.word_length();
         // Check if we're at the last column
-        if col == 4 {
+        if col + 1 == width {
             // Move to the first tile of the next row if not at the last row
             if row < self.tiles_grid.tiles.len() - 1 {
                 self.update_selected_tile(row + 1, 0);
                 // Update table state
-                self.table_state.select(Some((row + 1) * 5));
+                self.table_state.select(Some((row + 1) * width));
             }
         }
         // Do nothing if not at the last column
@@ -179,20 +596,21 @@ impl App {
     pub fn go_prev_row(&mut self) {
         // Get the coordinates of the selected cell
         let (row, col) = self.selected_tile;
+        let width = self.word_length();
         
         if self.current_screen == CurrentScreen::EditingTileChar{
-            // Move to the previous row if not the first row and if column is the first
-            if row > 0 || col == 0 {
-                self.update_selected_tile(row - 1, 4);
+            // Move to the last tile of the previous row if not the first row
+            if row > 0 {
+                self.update_selected_tile(row - 1, width - 1);
                 // Update row state in TableState
-                self.table_state.select(Some((row - 1) * 5 + 4));
+                self.table_state.select(Some((row - 1) * width + width - 1));
             }
         }else{
             // Move to the previous row if not the first row
             if row > 0 {
                 self.update_selected_tile(row - 1, col);
                 // Update row state in TableState
-                self.table_state.select(Some((row - 1) * 5 + col));
+                self.table_state.select(Some((row - 1) * width + col));
             }
         }
     }
@@ -200,24 +618,26 @@ impl App {
     pub fn go_prev_col(&mut self) {
         // Get the coordinates of the selected cell
         let (row, col) = self.selected_tile;
+        let width = self.word_length();
 
         // Move to the previous column if not the first column
         if col > 0 {
             self.update_selected_tile(row, col - 1);
             // Update column state in TableState
-            self.table_state.select(Some(row * 5 + col - 1));
+            self.table_state.select(Some(row * width + col - 1));
         }
     }
 
     pub fn go_next_col(&mut self) {
         // Get the coordinates of the selected cell
         let (row, col) = self.selected_tile;
+        let width = self.word_length();
 
         // Move to the next column if not the last column
-        if col < 4 {
+        if col + 1 < width {
             self.update_selected_tile(row, col + 1);
             // Update column state in TableState
-            self.table_state.select(Some(row * 5 + col + 1));
+            self.table_state.select(Some(row * width + col + 1));
         }
     }
 
@@ -258,48 +678,280 @@ impl App {
     //     self.next_possible_words = self.solver.get_next_possible_words(&word.to_lowercase(), &color_state);
     // }
 
-    pub fn calculate_next_possible_word(&mut self){
+    /// Submits the selected row to the solver and starts computing the next suggestions.
+    /// Returns false, with the reason in `status_message`, if the row was rejected.
+    pub fn calculate_next_possible_word(&mut self) -> bool {
         let current_row_tile: &Vec<Tile> = &self.tiles_grid.tiles[self.selected_tile.0];
 
         let word: String = current_row_tile.iter().map(|tile| tile.character).collect();
-        let color_state = self.get_color_state(current_row_tile);
-        
-        self.solver.add_used_word(&word.to_lowercase(), &color_state);
+        if let Some(col) = current_row_tile.iter().position(|tile| tile.character == ' ') {
+            self.status_message = Some(format!("Incomplete row: tile {} has no letter", col + 1));
+            return false;
+        }
 
-        let mut solver = self.solver.clone();
-        let word_clone = word.to_lowercase();
-        let color_state_clone = color_state.clone();
+        // Typos would mislead the solver, only words of the dictionary can be played
+        let guess = word.to_lowercase();
+        if !self.solver.is_valid_guess(&guess) {
+            self.did_you_mean = self.solver.closest_guesses(&guess, MAX_TYPO_DISTANCE, DID_YOU_MEAN_COUNT);
+            self.status_message = Some(match self.did_you_mean.first() {
+                Some(first) => format!(
+                    "{} is not in the dictionary, did you mean {}? (f) to use {}",
+                    word,
+                    self.did_you_mean.join(", ").to_uppercase(),
+                    first.to_uppercase()
+                ),
+                None => format!("{} is not in the dictionary", word),
+            });
+            return false;
+        }
+        self.did_you_mean.clear();
+
+        let color_state = match self.get_color_state(current_row_tile) {
+            Ok(pattern) => pattern,
+            Err(err) => {
+                self.status_message = Some(format!("Incomplete row: {}", err));
+                return false;
+            }
+        };
+
+        // A row that was already submitted is edited in place, the rest of the history is kept
+        let row = self.selected_tile.0;
+        let edited = self.submitted_rows.iter().position(|&r| r == row);
+
+        // In hard mode, refuse new rows that ignore the hints revealed so far
+        if self.solver.hard_mode && edited.is_none() {
+            if let Some(violation) = self.solver.hard_mode_violation(&guess) {
+                self.status_message = Some(format!("Hard mode: {} rejected, {}", word, violation));
+                return false;
+            }
+        }
+
+        let history_len = self.solver.history().len();
+        let result = match edited {
+            Some(index) => self.solver.replace_row(index, &guess, &color_state),
+            None => self.solver.add_used_word(&guess, &color_state),
+        };
+        match result {
+            Ok(()) => {
+                if self.solver.history().len() > history_len {
+                    // A new row can no longer be followed by the undone ones
+                    self.submitted_rows.push(row);
+                    self.undone_rows.clear();
+                }
+                self.conflict_tiles.clear();
+            }
+            Err(SolverError::InconsistentFeedback { conflict: Some(conflict), .. }) => {
+                self.show_conflict(&conflict);
+                return false;
+            }
+            Err(err) => {
+                self.status_message = Some(format!("Row rejected: {}", err));
+                return false;
+            }
+        }
+
+        // All greens: the row is recorded, but the game is over and there is nothing left to suggest
+        if color_state.is_solved() {
+            self.status_message = Some(format!("Solved: the word is {}", word));
+            self.next_possible_words.clear();
+            return true;
+        }
+        self.status_message = edited.map(|index| format!("Row {} updated", index + 1));
+
+        self.refresh_suggestions();
+        true
+    }
+
+    /// Replaces the letters of the selected row with the first word offered for
+    /// it by "did you mean", keeping its colors.
+    pub fn use_did_you_mean(&mut self) {
+        let Some(word) = self.did_you_mean.first().cloned() else {
+            return;
+        };
+        let row = self.selected_tile.0;
+        for (tile, letter) in self.tiles_grid.tiles[row].iter_mut().zip(word.chars()) {
+            tile.character = letter.to_uppercase().next().unwrap_or(letter);
+        }
+        self.did_you_mean.clear();
+        self.status_message = Some(format!("Row {} changed to {}", row + 1, word.to_uppercase()));
+    }
 
+    /// Removes the last submitted row from the solver, its tiles stay in the grid.
+    pub fn undo(&mut self) {
+        match self.solver.undo() {
+            Some((word, _)) => {
+                let row = self.submitted_rows.pop().unwrap_or_default();
+                self.undone_rows.push(row);
+                self.status_message = Some(format!("Undid row {} ({})", row + 1, word.to_uppercase()));
+                self.conflict_tiles.clear();
+                self.refresh_suggestions();
+            }
+            None => self.status_message = Some("Nothing to undo".to_string()),
+        }
+    }
+
+    /// Submits again the last row removed by `undo`.
+    pub fn redo(&mut self) {
+        match self.solver.redo() {
+            Ok(Some((word, _))) => {
+                let row = self.undone_rows.pop().unwrap_or_default();
+                self.submitted_rows.push(row);
+                self.status_message = Some(format!("Redid row {} ({})", row + 1, word.to_uppercase()));
+                self.refresh_suggestions();
+            }
+            Ok(None) => self.status_message = Some("Nothing to redo".to_string()),
+            Err(err) => self.status_message = Some(format!("Cannot redo: {}", err)),
+        }
+    }
+
+    // Ranks the next guesses for the current history in the background.
+    // Before the first guess, the best openers are suggested instead.
+    fn refresh_suggestions(&mut self) {
+        let solver = self.solver.clone();
+        let opener_cache = PathBuf::from(self.game.language.cache_file("openers"));
         let (tx, rx) = mpsc::channel();
         self.calculating_receiver = Some(rx);
         self.is_solving = true;
+        // The new suggestions are shown from the top
+        self.list_state.select(Some(0));
 
         std::thread::spawn(move || {
-            let next_possible_words = solver.get_next_possible_words(&word_clone, &color_state_clone);
-            tx.send(next_possible_words).unwrap();
+            let next_possible_words = if solver.history().is_empty() {
+                Ok(top_openers(&solver, &opener_cache))
+            } else {
+                solver.rank_guesses()
+            };
+            // The app may have moved on and dropped the receiver, that is fine
+            let _ = tx.send(next_possible_words);
         });
     }
 
-    pub fn get_color_state(&self, row: &Vec<Tile>) -> String{
-        let mut color_state = String::new();
-        for tile in row{
-            match tile.color {
-                TileColor::Normal => color_state.push('W'),
-                TileColor::CorrectPlace => color_state.push('G'),
-                TileColor::Absent => color_state.push('R'),
-                TileColor::WrongPlace => color_state.push('Y'),
+    // Highlights the conflicting tiles of the rejected row (the selected one) and
+    // the submitted rows, and suggests the tile flip that would fix them
+    fn show_conflict(&mut self, conflict: &Conflict) {
+        let current_row = self.selected_tile.0;
+        let grid_row = |history_row: usize| self.submitted_rows.get(history_row).copied().unwrap_or(current_row);
+
+        self.conflict_tiles = conflict.tiles.iter().map(|&(row, col)| (grid_row(row), col)).collect();
+
+        let other_rows: Vec<String> = conflict
+            .rows
+            .iter()
+            .map(|&row| grid_row(row))
+            .filter(|&row| row != current_row)
+            .map(|row| (row + 1).to_string())
+            .collect();
+        let mut message = if other_rows.is_empty() {
+            format!("Row {} cannot be right on its own", current_row + 1)
+        } else {
+            format!("Row {} contradicts row {}", current_row + 1, other_rows.join(", "))
+        };
+        if let Some(fix) = &conflict.fix {
+            message.push_str(&format!(
+                ": try making tile {} of row {} {}",
+                fix.position + 1,
+                grid_row(fix.row) + 1,
+                TileColor::from_state(fix.state).name()
+            ));
+        }
+        self.status_message = Some(message);
+    }
+
+    pub fn toggle_guess_pool(&mut self) {
+        self.solver.guess_pool = match self.solver.guess_pool {
+            GuessPool::Candidates => GuessPool::FullDictionary,
+            GuessPool::FullDictionary => GuessPool::Candidates,
+        };
+    }
+
+    pub fn toggle_lookahead(&mut self) {
+        self.solver.lookahead = match self.solver.lookahead {
+            Lookahead::Greedy => Lookahead::TwoStep { top_k: DEFAULT_LOOKAHEAD_TOP_K },
+            Lookahead::TwoStep { .. } => Lookahead::Greedy,
+        };
+    }
+
+    pub fn toggle_hard_mode(&mut self) {
+        self.solver.hard_mode = !self.solver.hard_mode;
+    }
+
+    pub fn go_prev_setting(&mut self) {
+        self.selected_setting = self.selected_setting.saturating_sub(1);
+    }
+
+    pub fn go_next_setting(&mut self) {
+        self.selected_setting = (self.selected_setting + 1).min(SolverConfig::KEYS.len() - 1);
+    }
+
+    /// Changes the selected setting by one step (`direction` is 1 or -1) and
+    /// applies it to the solver, the next calculation will use it.
+    pub fn adjust_setting(&mut self, direction: i32) {
+        let step = direction as f64;
+        match SolverConfig::KEYS[self.selected_setting] {
+            "strategy" => {
+                let current = STRATEGY_NAMES.iter().position(|&n| n == self.config.strategy).unwrap_or(0);
+                let next = cycle(current, STRATEGY_NAMES.len(), direction);
+                self.config.strategy = STRATEGY_NAMES[next].to_string();
+            }
+            "entropy_weight" => {
+                self.config.entropy_weight = (self.config.entropy_weight + 0.05 * step).max(0.0);
+            }
+            "frequency_weight" => {
+                self.config.frequency_weight = (self.config.frequency_weight + 0.05 * step).max(0.0);
+            }
+            "normalization" => {
+                let current = Normalization::ALL.iter().position(|&n| n == self.config.normalization).unwrap_or(0);
+                self.config.normalization = Normalization::ALL[cycle(current, Normalization::ALL.len(), direction)];
+            }
+            "bits_per_guess" => {
+                self.config.bits_per_guess = (self.config.bits_per_guess + 0.25 * step).max(0.25);
             }
+            _ => {}
         }
-        color_state
+        self.solver.strategy = self.config.build_strategy();
+        self.solver.normalization = self.config.normalization;
+    }
+
+    pub fn save_config(&mut self) {
+        self.status_message = Some(match self.config.save(&self.config_path) {
+            Ok(()) => format!("Settings saved to {}", self.config_path.display()),
+            Err(e) => format!("Settings not saved: {}", e),
+        });
+    }
+
+    /// Reads the feedback of a row, failing if some tile has no color yet.
+    pub fn get_color_state(&self, row: &[Tile]) -> Result<Pattern, PatternError> {
+        let tiles: Vec<Option<TileState>> = row.iter().map(|tile| tile.color.to_state()).collect();
+        Pattern::from_tiles(&tiles)
     }
 
     pub fn update(&mut self) {
+        // Attach the pattern table once it is ready
+        if let Some(receiver) = &self.pattern_table_receiver {
+            match receiver.try_recv() {
+                Ok(table) => {
+                    self.solver.set_pattern_table(table);
+                    self.pattern_table_receiver = None;
+                }
+                Err(mpsc::TryRecvError::Empty) => {}
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    self.pattern_table_receiver = None;
+                }
+            }
+        }
+
         // Check for completed calculations
         if let Some(receiver) = &self.calculating_receiver {
             match receiver.try_recv() {
-                Ok(words) => {
-                    // Calculation completed successfully
-                    self.next_possible_words = words;
+                Ok(result) => {
+                    // Calculation completed, successfully or not
+                    self.next_possible_words = match result {
+                        Ok(words) => words,
+                        Err(err) => {
+                            self.status_message = Some(format!("Error: {}", err));
+                            Vec::new()
+                        }
+                    };
                     self.calculating_receiver = None;
                     self.is_solving = false;
                 
@@ -323,3 +975,23 @@ impl App {
         }
     }
 }
+
+// Best opening words, ranked once and cached on disk, with their scores
+fn top_openers(solver: &Solver, cache_path: &Path) -> Vec<ScoredGuess> {
+    let openers: Vec<String> = solver
+        .cached_openers(cache_path)
+        .into_iter()
+        .take(TOP_OPENERS)
+        .map(|(word, _)| word)
+        .collect();
+    solver.score_guesses(&openers)
+}
+
+// Moves an index one step forward or backward, wrapping around
+fn cycle(index: usize, len: usize, direction: i32) -> usize {
+    if direction < 0 {
+        (index + len - 1) % len
+    } else {
+        (index + 1) % len
+    }
+}
@@ -0,0 +1,285 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::error::SolverError;
+use crate::feedback::MAX_WORD_LENGTH;
+use crate::language::{Language, ITALIAN, LANGUAGES};
+use crate::strategy::{
+    strategy_by_name, BlendStrategy, ExpectedGuessesStrategy, Normalization, Strategy, STRATEGY_NAMES,
+};
+use crate::loader;
+use crate::words::WordLists;
+
+/// Number of guesses allowed by default, as in the original game.
+pub const DEFAULT_MAX_ATTEMPTS: usize = 6;
+
+/// Scoring settings that can be tuned without recompiling, from a config file,
+/// command line flags or the TUI settings panel.
+///
+/// The config file holds one `key = value` setting per line, lines starting
+/// with `#` are comments. The keys are the ones in `KEYS`, the matching command
+/// line flags use dashes instead of underscores (e.g. `--entropy-weight 0.7`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolverConfig {
+    pub strategy: String,
+    pub entropy_weight: f64,
+    pub frequency_weight: f64,
+    pub normalization: Normalization,
+    pub bits_per_guess: f64,
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        let blend = BlendStrategy::default();
+        SolverConfig {
+            strategy: "blend".to_string(),
+            entropy_weight: blend.entropy_weight,
+            frequency_weight: blend.frequency_weight,
+            normalization: Normalization::Max,
+            bits_per_guess: ExpectedGuessesStrategy::default().bits_per_guess,
+        }
+    }
+}
+
+impl SolverConfig {
+    /// Names of the settings, in the order they are written and displayed.
+    pub const KEYS: [&'static str; 5] = [
+        "strategy",
+        "entropy_weight",
+        "frequency_weight",
+        "normalization",
+        "bits_per_guess",
+    ];
+
+    /// Reads the settings in `path` on top of the current ones.
+    pub fn load(&mut self, path: &Path) -> Result<(), String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+
+        for (number, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("{}:{}: expected key = value", path.display(), number + 1))?;
+            self.set(key.trim(), value.trim())
+                .map_err(|e| format!("{}:{}: {}", path.display(), number + 1, e))?;
+        }
+        Ok(())
+    }
+
+    /// Writes the settings to `path`.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content: String = Self::KEYS
+            .iter()
+            .map(|key| format!("{} = {}\n", key, self.get(key)))
+            .collect();
+        fs::write(path, content).map_err(|e| format!("cannot write {}: {}", path.display(), e))
+    }
+
+    /// Changes a setting, parsing its value.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let parse_number = |value: &str| {
+            value
+                .parse::<f64>()
+                .ok()
+                .filter(|v| v.is_finite() && *v >= 0.0)
+                .ok_or_else(|| format!("invalid value '{}' for {}, expected a non-negative number", value, key))
+        };
+
+        match key {
+            "strategy" => {
+                if !STRATEGY_NAMES.contains(&value) {
+                    return Err(format!(
+                        "unknown strategy '{}', expected one of: {}",
+                        value,
+                        STRATEGY_NAMES.join(", ")
+                    ));
+                }
+                self.strategy = value.to_string();
+            }
+            "entropy_weight" => self.entropy_weight = parse_number(value)?,
+            "frequency_weight" => self.frequency_weight = parse_number(value)?,
+            "normalization" => {
+                self.normalization = Normalization::from_name(value).ok_or_else(|| {
+                    format!("unknown normalization '{}', expected max, min-max or none", value)
+                })?;
+            }
+            "bits_per_guess" => self.bits_per_guess = parse_number(value)?,
+            _ => return Err(format!("unknown setting '{}'", key)),
+        }
+        Ok(())
+    }
+
+    /// Returns a setting formatted as in the config file.
+    pub fn get(&self, key: &str) -> String {
+        match key {
+            "strategy" => self.strategy.clone(),
+            "entropy_weight" => format!("{:.2}", self.entropy_weight),
+            "frequency_weight" => format!("{:.2}", self.frequency_weight),
+            "normalization" => self.normalization.name().to_string(),
+            "bits_per_guess" => format!("{:.2}", self.bits_per_guess),
+            _ => String::new(),
+        }
+    }
+
+    /// Builds the configured strategy with its parameters.
+    pub fn build_strategy(&self) -> Arc<dyn Strategy> {
+        match self.strategy.as_str() {
+            "blend" => Arc::new(BlendStrategy {
+                entropy_weight: self.entropy_weight,
+                frequency_weight: self.frequency_weight,
+            }),
+            "expected-guesses" => Arc::new(ExpectedGuessesStrategy {
+                bits_per_guess: self.bits_per_guess,
+            }),
+            name => strategy_by_name(name).unwrap_or_else(|| Arc::new(BlendStrategy::default())),
+        }
+    }
+
+    /// Maps a command line flag to the setting it changes,
+    /// e.g. `--entropy-weight` to `entropy_weight`.
+    pub fn flag_key(flag: &str) -> Option<&'static str> {
+        if flag == "-s" {
+            return Some("strategy");
+        }
+        let name = flag.strip_prefix("--")?.replace('-', "_");
+        Self::KEYS.into_iter().find(|key| *key == name)
+    }
+}
+
+/// Shape of the game being played and the word lists it is played with, set
+/// from the `--language`, `--word-length`, `--max-attempts`, `--answers`,
+/// `--guesses` and `--freqs` flags.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameConfig {
+    /// Language whose bundled lists are played, unless replaced by files.
+    pub language: &'static Language,
+    /// Length of the words, the one of the word lists if not set.
+    pub word_length: Option<usize>,
+    pub max_attempts: usize,
+    /// Files replacing the bundled answers, guesses and frequencies.
+    pub answers: Option<PathBuf>,
+    pub guesses: Option<PathBuf>,
+    pub frequencies: Option<PathBuf>,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig {
+            language: &ITALIAN,
+            word_length: None,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            answers: None,
+            guesses: None,
+            frequencies: None,
+        }
+    }
+}
+
+impl GameConfig {
+    /// Applies a command line flag, returning `Ok(false)` if the flag is not a game one.
+    pub fn set_flag(&mut self, flag: &str, value: &str) -> Result<bool, String> {
+        let parse_count = |value: &str, max: usize| {
+            value
+                .parse::<usize>()
+                .ok()
+                .filter(|&v| (1..=max).contains(&v))
+                .ok_or_else(|| format!("invalid value '{}' for {}, expected a number from 1 to {}", value, flag, max))
+        };
+
+        match flag {
+            "--language" => {
+                self.language = Language::by_name(value).ok_or_else(|| {
+                    let names: Vec<&str> = LANGUAGES.iter().map(|language| language.name).collect();
+                    format!("unknown language '{}', expected one of: {}", value, names.join(", "))
+                })?;
+            }
+            "--word-length" => self.word_length = Some(parse_count(value, MAX_WORD_LENGTH)?),
+            "--max-attempts" => self.max_attempts = parse_count(value, u8::MAX as usize)?,
+            "--answers" => self.answers = Some(PathBuf::from(value)),
+            "--guesses" => self.guesses = Some(PathBuf::from(value)),
+            "--freqs" => self.frequencies = Some(PathBuf::from(value)),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Reads the word lists and keeps the words of the configured length,
+    /// failing if there are none to play with. Without a configured length, an
+    /// answers file must hold words of a single length.
+    pub fn word_lists(&self) -> Result<WordLists, SolverError> {
+        let lists = WordLists::load(
+            self.language,
+            self.answers.as_deref(),
+            self.guesses.as_deref(),
+            self.frequencies.as_deref(),
+        )?;
+        let word_length = match (self.word_length, self.answers.as_deref()) {
+            (Some(length), _) => length,
+            (None, Some(path)) => loader::word_length(path, self.language)?.unwrap_or_else(|| lists.word_length()),
+            (None, None) => lists.word_length(),
+        };
+        let lists = lists.with_length(word_length);
+        if lists.answers.is_empty() {
+            return Err(SolverError::NoWordsOfLength { length: word_length });
+        }
+        Ok(lists)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A path in the temporary directory, named after the test
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("parole-bot-{}-{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn saved_settings_load_back() {
+        let mut config = SolverConfig::default();
+        config.set("strategy", "minimax").unwrap();
+        config.set("entropy_weight", "0.25").unwrap();
+        config.set("frequency_weight", "1.5").unwrap();
+        config.set("normalization", "min-max").unwrap();
+        config.set("bits_per_guess", "3.75").unwrap();
+
+        let path = temp_path("round-trip.conf");
+        config.save(&path).unwrap();
+        let mut loaded = SolverConfig::default();
+        loaded.load(&path).unwrap();
+        assert_eq!(loaded, config);
+        for key in SolverConfig::KEYS {
+            assert_eq!(loaded.get(key), config.get(key));
+        }
+    }
+
+    #[test]
+    fn unknown_keys_and_invalid_values_are_rejected() {
+        let mut config = SolverConfig::default();
+        assert_eq!(config.set("colour", "red"), Err("unknown setting 'colour'".to_string()));
+        assert!(config.set("strategy", "random").is_err());
+        assert!(config.set("entropy_weight", "-1").is_err());
+        assert!(config.set("frequency_weight", "many").is_err());
+        assert!(config.set("bits_per_guess", "inf").is_err());
+        assert!(config.set("normalization", "log").is_err());
+        assert_eq!(config, SolverConfig::default());
+    }
+
+    #[test]
+    fn load_reports_the_line_of_a_bad_setting() {
+        let path = temp_path("bad.conf");
+        fs::write(&path, "# tuned\nstrategy = minimax\nentropy_weight 0.5\n").unwrap();
+        let err = SolverConfig::default().load(&path).unwrap_err();
+        assert!(err.ends_with(":3: expected key = value"), "{}", err);
+
+        fs::write(&path, "strategy = minimax\ncolour = red\n").unwrap();
+        let err = SolverConfig::default().load(&path).unwrap_err();
+        assert!(err.ends_with(":2: unknown setting 'colour'"), "{}", err);
+    }
+}
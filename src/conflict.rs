@@ -0,0 +1,168 @@
+use crate::feedback::{feedback_pattern, Pattern, TileState};
+
+/// Explains why a `(guess, pattern)` history cannot be satisfied by any word.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Conflict {
+    /// Rows of the history that cannot hold together, each of them being needed
+    /// for the contradiction. Rows are indexes in the history, in increasing order.
+    pub rows: Vec<usize>,
+    /// `(row, position)` of the tiles of those rows that are enough to cause the
+    /// contradiction, each of them being needed for it.
+    pub tiles: Vec<(usize, usize)>,
+    /// A single tile flip that makes the whole history consistent again, if any.
+    pub fix: Option<TileFix>,
+}
+
+/// Changing the tile at `position` of `row` to `state`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TileFix {
+    pub row: usize,
+    pub position: usize,
+    pub state: TileState,
+    /// Number of words left once the tile is flipped.
+    pub candidates: usize,
+}
+
+// A tile of the history: (row, position, state)
+type TileConstraint = (usize, usize, TileState);
+
+/// Looks for a contradiction in `history` given the words that can be the answer.
+/// Returns `None` if at least one word is consistent with every row.
+pub fn find_conflict(history: &[(String, Pattern)], words: &[String]) -> Option<Conflict> {
+    // Feedback of every row against every word, computed once
+    let feedbacks: Vec<Vec<Pattern>> = history
+        .iter()
+        .map(|(guess, _)| words.iter().map(|word| feedback_pattern(guess, word)).collect())
+        .collect();
+    let count_matches = |tiles: &[TileConstraint]| -> usize {
+        (0..words.len())
+            .filter(|&w| {
+                tiles
+                    .iter()
+                    .all(|&(row, i, state)| feedbacks[row][w].tiles().get(i) == Some(&state))
+            })
+            .count()
+    };
+
+    let all_tiles: Vec<TileConstraint> = history
+        .iter()
+        .enumerate()
+        .flat_map(|(row, (_, pattern))| {
+            pattern.tiles().iter().enumerate().map(move |(i, &state)| (row, i, state))
+        })
+        .collect();
+    if count_matches(&all_tiles) > 0 {
+        return None;
+    }
+
+    // Drop every row that is not needed for the contradiction, the oldest first,
+    // so the rows entered last (the likely culprits) are kept
+    let mut rows: Vec<usize> = (0..history.len()).collect();
+    let mut index = 0;
+    while index < rows.len() {
+        let mut without = rows.clone();
+        without.remove(index);
+        let tiles: Vec<TileConstraint> =
+            all_tiles.iter().filter(|(row, _, _)| without.contains(row)).copied().collect();
+        if count_matches(&tiles) == 0 {
+            rows = without;
+        } else {
+            index += 1;
+        }
+    }
+
+    // Same for the tiles of the remaining rows. The result depends on the order
+    // the tiles are tried in, so try both orders and keep the smaller explanation.
+    let row_tiles: Vec<TileConstraint> =
+        all_tiles.iter().filter(|(row, _, _)| rows.contains(row)).copied().collect();
+    let forward = minimal_subset(row_tiles.clone(), &count_matches);
+    let mut reversed = row_tiles;
+    reversed.reverse();
+    let mut backward = minimal_subset(reversed, &count_matches);
+    backward.reverse();
+    let tiles = if backward.len() < forward.len() { backward } else { forward };
+
+    // A fix has to change one of the conflicting tiles. Prefer the latest row,
+    // then the flip leaving the most words.
+    let mut fixes: Vec<TileFix> = Vec::new();
+    for &(row, position, current) in &tiles {
+        for state in [TileState::Absent, TileState::Present, TileState::Correct] {
+            if state == current {
+                continue;
+            }
+            let flipped: Vec<TileConstraint> = all_tiles
+                .iter()
+                .map(|&tile| if tile == (row, position, current) { (row, position, state) } else { tile })
+                .collect();
+            let candidates = count_matches(&flipped);
+            if candidates > 0 {
+                fixes.push(TileFix { row, position, state, candidates });
+            }
+        }
+    }
+    fixes.sort_by(|a, b| b.row.cmp(&a.row).then(b.candidates.cmp(&a.candidates)));
+
+    Some(Conflict {
+        rows,
+        tiles: tiles.iter().map(|&(row, i, _)| (row, i)).collect(),
+        fix: fixes.into_iter().next(),
+    })
+}
+
+// Removes, one at a time, every tile that is not needed to keep `tiles` unsatisfiable
+fn minimal_subset<F>(mut tiles: Vec<TileConstraint>, count_matches: &F) -> Vec<TileConstraint>
+where
+    F: Fn(&[TileConstraint]) -> usize,
+{
+    let mut index = 0;
+    while index < tiles.len() {
+        let mut without = tiles.clone();
+        without.remove(index);
+        if count_matches(&without) == 0 {
+            tiles = without;
+        } else {
+            index += 1;
+        }
+    }
+    tiles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::WordLists;
+
+    fn history(rows: &[(&str, &str)]) -> Vec<(String, Pattern)> {
+        rows.iter().map(|&(guess, pattern)| (guess.to_string(), pattern.parse().unwrap())).collect()
+    }
+
+    #[test]
+    fn contradiction_is_narrowed_to_rows_tiles_and_a_fix() {
+        let answers = WordLists::embedded().answers;
+        let mut rows = history(&[("seria", "RRGRY"), ("carta", "GGGGG")]);
+        let conflict = find_conflict(&rows, &answers).expect("the rows contradict each other");
+        assert_eq!(conflict.rows, vec![0, 1]);
+
+        // The yellow and the green A in the last position are enough on their own
+        let mut tiles = conflict.tiles.clone();
+        tiles.sort();
+        assert_eq!(tiles, vec![(0, 4), (1, 4)]);
+
+        let fix = conflict.fix.expect("a single flip fixes the history");
+        assert!(fix.candidates > 0);
+        let mut flipped = rows[fix.row].1.tiles().to_vec();
+        flipped[fix.position] = fix.state;
+        rows[fix.row].1 = Pattern::new(flipped).unwrap();
+        assert_eq!(find_conflict(&rows, &answers), None);
+    }
+
+    #[test]
+    fn consistent_history_has_no_conflict() {
+        let answers = WordLists::embedded().answers;
+        let rows = vec![
+            ("seria".to_string(), feedback_pattern("seria", "carta")),
+            ("palla".to_string(), feedback_pattern("palla", "carta")),
+        ];
+        assert_eq!(find_conflict(&rows, &answers), None);
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::feedback::{Pattern, TileState};
+
+/// Letter constraints accumulated from the `(guess, pattern)` history.
+///
+/// The model mirrors the way `feedback::feedback_pattern` scores a guess:
+/// - for each letter, the number of 'G' and 'Y' tiles is the minimum number of
+///   occurrences in the answer;
+/// - if the same letter also received an 'R' tile, that number is exact, so it
+///   becomes the maximum as well;
+/// - a 'G' fixes the letter at that position, while 'Y' and 'R' exclude it there.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Constraints {
+    word_length: usize,
+    // Letter known to be at each position (green).
+    fixed: Vec<Option<char>>,
+    // Letters known not to be at each position (yellow or red).
+    excluded: Vec<HashSet<char>>,
+    // Minimum number of occurrences for each letter.
+    min_counts: HashMap<char, usize>,
+    // Maximum number of occurrences, only known once a letter got a red tile.
+    max_counts: HashMap<char, usize>,
+}
+
+impl Constraints {
+    pub fn new(word_length: usize) -> Self {
+        Constraints {
+            word_length,
+            fixed: vec![None; word_length],
+            excluded: vec![HashSet::new(); word_length],
+            min_counts: HashMap::new(),
+            max_counts: HashMap::new(),
+        }
+    }
+
+    /// Adds the information carried by a single `(guess, pattern)` pair.
+    pub fn add_feedback(&mut self, guess: &str, pattern: &Pattern) {
+        // Per letter: (number of G/Y tiles, got a red tile)
+        let mut letters: HashMap<char, (usize, bool)> = HashMap::new();
+
+        for (i, (c, state)) in guess.chars().zip(pattern.tiles()).enumerate() {
+            if i >= self.word_length {
+                break;
+            }
+            let entry = letters.entry(c).or_insert((0, false));
+            match state {
+                TileState::Correct => {
+                    self.fixed[i] = Some(c);
+                    entry.0 += 1;
+                }
+                TileState::Present => {
+                    self.excluded[i].insert(c);
+                    entry.0 += 1;
+                }
+                TileState::Absent => {
+                    self.excluded[i].insert(c);
+                    entry.1 = true;
+                }
+            }
+        }
+
+        for (c, (matched, has_red)) in letters {
+            let min = self.min_counts.entry(c).or_insert(0);
+            *min = (*min).max(matched);
+
+            // A red tile means every occurrence in the answer was already matched
+            if has_red {
+                let max = self.max_counts.entry(c).or_insert(matched);
+                *max = (*max).min(matched);
+            }
+        }
+    }
+
+    /// Returns true if `word` could still be the answer.
+    pub fn matches(&self, word: &str) -> bool {
+        let chars: Vec<char> = word.chars().collect();
+        if chars.len() != self.word_length {
+            return false;
+        }
+
+        for (i, &c) in chars.iter().enumerate() {
+            if let Some(fixed) = self.fixed[i] {
+                if fixed != c {
+                    return false;
+                }
+            }
+            if self.excluded[i].contains(&c) {
+                return false;
+            }
+        }
+
+        for (&c, &min) in &self.min_counts {
+            if min > 0 && chars.iter().filter(|&&ch| ch == c).count() < min {
+                return false;
+            }
+        }
+        for (&c, &max) in &self.max_counts {
+            if chars.iter().filter(|&&ch| ch == c).count() > max {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Checks `guess` against the hard-mode rules: every green letter must be reused
+    /// in the same position and every revealed letter must appear in the guess.
+    /// Returns a description of the first broken rule, if any.
+    pub fn hard_mode_violation(&self, guess: &str) -> Option<String> {
+        let chars: Vec<char> = guess.chars().collect();
+
+        for (i, fixed) in self.fixed.iter().enumerate() {
+            if let Some(c) = fixed {
+                if chars.get(i) != Some(c) {
+                    return Some(format!(
+                        "letter {} must be {}",
+                        i + 1,
+                        c.to_uppercase()
+                    ));
+                }
+            }
+        }
+
+        // Report the letters in alphabetical order so the message is stable
+        let mut required: Vec<(&char, &usize)> = self.min_counts.iter().filter(|(_, &min)| min > 0).collect();
+        required.sort();
+        for (&c, &min) in required {
+            if chars.iter().filter(|&&ch| ch == c).count() < min {
+                return Some(if min == 1 {
+                    format!("guess must contain {}", c.to_uppercase())
+                } else {
+                    format!("guess must contain {} at least {} times", c.to_uppercase(), min)
+                });
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constraints(guess: &str, pattern: &str) -> Constraints {
+        let mut constraints = Constraints::new(5);
+        constraints.add_feedback(guess, &pattern.parse().unwrap());
+        constraints
+    }
+
+    #[test]
+    fn greens_must_stay_in_place() {
+        let constraints = constraints("seria", "GRRRR");
+        assert_eq!(constraints.hard_mode_violation("asset"), Some("letter 1 must be S".to_string()));
+        assert_eq!(constraints.hard_mode_violation("sacco"), None);
+    }
+
+    #[test]
+    fn yellows_must_be_reused() {
+        let constraints = constraints("seria", "RRRRY");
+        assert_eq!(constraints.hard_mode_violation("colto"), Some("guess must contain A".to_string()));
+        assert_eq!(constraints.hard_mode_violation("canto"), None);
+    }
+
+    #[test]
+    fn repeated_letters_must_reach_their_count() {
+        // Against "assai": two S are revealed, one of them green in the middle
+        let constraints = constraints("sasso", "YYGRR");
+        assert_eq!(
+            constraints.hard_mode_violation("rasta"),
+            Some("guess must contain S at least 2 times".to_string())
+        );
+        assert_eq!(constraints.hard_mode_violation("assai"), None);
+    }
+}
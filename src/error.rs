@@ -0,0 +1,76 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+use crate::conflict::Conflict;
+use crate::feedback::Pattern;
+
+/// Errors returned by the public `Solver` API.
+#[derive(Debug)]
+pub enum SolverError {
+    /// A guess or a pattern does not have the length of the words being played.
+    InvalidLength { input: String, expected: usize, found: usize },
+    /// A guess contains a character that cannot be part of a word.
+    InvalidCharacter { word: String, character: char },
+    /// No candidate answer could have produced this feedback, given the rest of
+    /// the history. `conflict` tells which rows and tiles of the rejected history
+    /// contradict each other, `guess` and `pattern` being the latest row involved.
+    InconsistentFeedback { guess: String, pattern: Pattern, conflict: Option<Box<Conflict>> },
+    /// There are no candidate answers left to rank the guesses against.
+    EmptyCandidateSet,
+    /// The word lists have no answer of the length being played.
+    NoWordsOfLength { length: usize },
+    /// A row of the history was referenced by an index past its end.
+    NoSuchRow { index: usize, rows: usize },
+    /// A word list file could not be read: a word with invalid characters or
+    /// too many letters, a bad frequency or a malformed file. `line` is `None`
+    /// when the position is not known, e.g. in JSON files.
+    InvalidWordList { path: PathBuf, line: Option<usize>, reason: String },
+    /// Reading or writing a file failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolverError::InvalidLength { input, expected, found } => {
+                write!(f, "'{}' has {} letters, expected {}", input, found, expected)
+            }
+            SolverError::InvalidCharacter { word, character } => {
+                write!(f, "'{}' contains the invalid character '{}'", word, character)
+            }
+            SolverError::InconsistentFeedback { guess, pattern, .. } => write!(
+                f,
+                "no word matches {} for '{}' together with the previous rows",
+                pattern, guess
+            ),
+            SolverError::EmptyCandidateSet => write!(f, "no candidate words left"),
+            SolverError::NoWordsOfLength { length } => {
+                write!(f, "there are no {}-letter answers in the word lists", length)
+            }
+            SolverError::NoSuchRow { index, rows } => {
+                write!(f, "there is no row {}, only {} were played", index + 1, rows)
+            }
+            SolverError::InvalidWordList { path, line: Some(line), reason } => {
+                write!(f, "{}:{}: {}", path.display(), line, reason)
+            }
+            SolverError::InvalidWordList { path, line: None, reason } => write!(f, "{}: {}", path.display(), reason),
+            SolverError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SolverError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SolverError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SolverError {
+    fn from(err: io::Error) -> Self {
+        SolverError::Io(err)
+    }
+}
@@ -0,0 +1,288 @@
+//! Canonical scoring of a guess against an answer, shared by the solver and
+//! the simulations so that both play exactly the same game.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Feedback pattern encoded in base 3: digit `i` is the state of the tile at
+/// position `i` (0 = 'R', 1 = 'Y', 2 = 'G').
+pub type PatternCode = u16;
+
+/// Longest word that can be encoded in a `PatternCode` (3^10 < 2^16).
+pub const MAX_WORD_LENGTH: usize = 10;
+
+/// Number of distinct pattern codes for words of the given length, useful to
+/// size histograms.
+pub fn pattern_count(word_length: usize) -> usize {
+    3usize.pow(word_length.min(MAX_WORD_LENGTH) as u32)
+}
+
+/// Scores `guess` against `answer` and returns the encoded pattern.
+/// Greens are matched first, then yellows are assigned left to right, each one
+/// consuming an occurrence of the letter in the answer.
+pub fn feedback_code(guess: &str, answer: &str) -> PatternCode {
+    let mut guess_chars = [' '; MAX_WORD_LENGTH];
+    let mut answer_chars = ['\0'; MAX_WORD_LENGTH];
+    let mut length = 0;
+    for (i, (g, a)) in guess.chars().zip(answer.chars()).take(MAX_WORD_LENGTH).enumerate() {
+        guess_chars[i] = g;
+        answer_chars[i] = a;
+        length = i + 1;
+    }
+
+    let mut states = [0u8; MAX_WORD_LENGTH];
+    // First pass: mark greens.
+    for i in 0..length {
+        if guess_chars[i] == answer_chars[i] {
+            states[i] = 2;
+            // Mark this letter as used.
+            answer_chars[i] = '\0';
+        }
+    }
+    // Second pass: mark yellows.
+    for i in 0..length {
+        if states[i] == 2 {
+            continue;
+        }
+        if let Some(pos) = answer_chars[..length].iter().position(|&c| c == guess_chars[i]) {
+            states[i] = 1;
+            // Mark the matched letter as used.
+            answer_chars[pos] = '\0';
+        }
+    }
+
+    states[..length].iter().rev().fold(0, |code, &state| code * 3 + state as PatternCode)
+}
+
+/// Feedback on a single tile of a guess.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TileState {
+    /// The letter is not in the answer (red/black, 'R').
+    Absent,
+    /// The letter is in the answer at another position (yellow, 'Y').
+    Present,
+    /// The letter is at the right position (green, 'G').
+    Correct,
+}
+
+impl TileState {
+    /// Digit of the state in a `PatternCode`.
+    pub fn digit(&self) -> u8 {
+        match self {
+            TileState::Absent => 0,
+            TileState::Present => 1,
+            TileState::Correct => 2,
+        }
+    }
+
+    pub fn from_digit(digit: u8) -> Option<Self> {
+        match digit {
+            0 => Some(TileState::Absent),
+            1 => Some(TileState::Present),
+            2 => Some(TileState::Correct),
+            _ => None,
+        }
+    }
+
+    /// Letter used to write the state, as in "RYG" patterns.
+    pub fn letter(&self) -> char {
+        match self {
+            TileState::Absent => 'R',
+            TileState::Present => 'Y',
+            TileState::Correct => 'G',
+        }
+    }
+
+    /// Square used by the game when sharing results.
+    pub fn emoji(&self) -> char {
+        match self {
+            TileState::Absent => '⬛',
+            TileState::Present => '🟨',
+            TileState::Correct => '🟩',
+        }
+    }
+
+    // Reads a tile in any of the supported notations: Ok(None) is a tile that
+    // has not been colored yet, Err(()) a symbol that is not a tile at all.
+    fn parse(symbol: char) -> Result<Option<Self>, ()> {
+        match symbol {
+            'R' | 'r' | 'B' | 'b' | '0' | '⬛' | '⬜' => Ok(Some(TileState::Absent)),
+            'Y' | 'y' | '1' | '🟨' => Ok(Some(TileState::Present)),
+            'G' | 'g' | '2' | '🟩' => Ok(Some(TileState::Correct)),
+            'W' | 'w' | '?' | '_' | '.' => Ok(None),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Why a pattern could not be built.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PatternError {
+    /// The pattern has no tiles.
+    Empty,
+    /// The pattern has more tiles than `MAX_WORD_LENGTH`.
+    TooLong { length: usize },
+    /// A tile has not been colored yet (position counted from 1).
+    Incomplete { position: usize },
+    /// A symbol is not a tile in any supported notation (position counted from 1).
+    InvalidSymbol { position: usize, symbol: char },
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatternError::Empty => write!(f, "the pattern is empty"),
+            PatternError::TooLong { length } => write!(
+                f,
+                "the pattern has {} tiles, at most {} are supported",
+                length, MAX_WORD_LENGTH
+            ),
+            PatternError::Incomplete { position } => write!(f, "tile {} has no color", position),
+            PatternError::InvalidSymbol { position, symbol } => {
+                write!(f, "'{}' at tile {} is not a color", symbol, position)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+/// The feedback on a whole guess, one `TileState` per letter.
+///
+/// Patterns can be parsed from 'R'/'Y'/'G' letters ("RYGRR"), the squares of a
+/// shared result ("⬛🟨🟩⬛⬛") or 0/1/2 digits ("01200"), and are displayed
+/// as letters. `code` packs a pattern in a single `PatternCode`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Pattern {
+    tiles: Vec<TileState>,
+}
+
+impl Pattern {
+    pub fn new(tiles: Vec<TileState>) -> Result<Self, PatternError> {
+        if tiles.is_empty() {
+            return Err(PatternError::Empty);
+        }
+        if tiles.len() > MAX_WORD_LENGTH {
+            return Err(PatternError::TooLong { length: tiles.len() });
+        }
+        Ok(Pattern { tiles })
+    }
+
+    /// Builds a pattern from a row of tiles, where `None` is a tile without a
+    /// color: such rows are rejected as a whole.
+    pub fn from_tiles(tiles: &[Option<TileState>]) -> Result<Self, PatternError> {
+        let tiles = tiles
+            .iter()
+            .enumerate()
+            .map(|(i, tile)| tile.ok_or(PatternError::Incomplete { position: i + 1 }))
+            .collect::<Result<Vec<TileState>, PatternError>>()?;
+        Self::new(tiles)
+    }
+
+    /// Decodes a pattern code for a word of the given length.
+    pub fn from_code(code: PatternCode, word_length: usize) -> Self {
+        let mut code = code;
+        let tiles = (0..word_length.min(MAX_WORD_LENGTH))
+            .map(|_| {
+                let digit = (code % 3) as u8;
+                code /= 3;
+                TileState::from_digit(digit).unwrap_or(TileState::Absent)
+            })
+            .collect();
+        Pattern { tiles }
+    }
+
+    /// Encodes the pattern in base 3, digit `i` being the state of tile `i`.
+    pub fn code(&self) -> PatternCode {
+        self.tiles.iter().rev().fold(0, |code, tile| code * 3 + tile.digit() as PatternCode)
+    }
+
+    pub fn tiles(&self) -> &[TileState] {
+        &self.tiles
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// Returns true if every tile is green, i.e. the guess was the answer.
+    pub fn is_solved(&self) -> bool {
+        self.tiles.iter().all(|&tile| tile == TileState::Correct)
+    }
+
+    /// Writes the pattern with the squares used to share results.
+    pub fn to_emoji(&self) -> String {
+        self.tiles.iter().map(TileState::emoji).collect()
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Emoji may carry a variation selector, which is not a tile
+        let tiles = s
+            .trim()
+            .chars()
+            .filter(|&c| c != '\u{fe0f}')
+            .enumerate()
+            .map(|(i, symbol)| {
+                TileState::parse(symbol).map_err(|_| PatternError::InvalidSymbol { position: i + 1, symbol })
+            })
+            .collect::<Result<Vec<Option<TileState>>, PatternError>>()?;
+        if tiles.len() > MAX_WORD_LENGTH {
+            return Err(PatternError::TooLong { length: tiles.len() });
+        }
+        Self::from_tiles(&tiles)
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.tiles.iter().try_for_each(|tile| write!(f, "{}", tile.letter()))
+    }
+}
+
+/// Scores `guess` against `answer`.
+///
+/// Repeated letters are only marked present as many times as they appear in
+/// the answer, after the greens have been accounted for.
+pub fn feedback_pattern(guess: &str, answer: &str) -> Pattern {
+    let length = guess.chars().count().min(answer.chars().count());
+    Pattern::from_code(feedback_code(guess, answer), length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_letters_are_colored_once_per_occurrence() {
+        let cases = [
+            ("sassi", "rossa", "RYGGR"),
+            ("lolla", "palla", "RRGGG"),
+            ("palla", "lapis", "YGYRR"),
+            ("sasso", "assai", "YYGRR"),
+            ("seria", "seria", "GGGGG"),
+        ];
+        for (guess, answer, expected) in cases {
+            let expected: Pattern = expected.parse().unwrap();
+            assert_eq!(feedback_code(guess, answer), expected.code(), "'{}' against '{}'", guess, answer);
+            assert_eq!(feedback_pattern(guess, answer), expected, "'{}' against '{}'", guess, answer);
+        }
+    }
+
+    #[test]
+    fn patterns_round_trip_through_codes_letters_and_emoji() {
+        for code in 0..pattern_count(5) as PatternCode {
+            let pattern = Pattern::from_code(code, 5);
+            assert_eq!(pattern.code(), code);
+            assert_eq!(pattern.to_string().parse::<Pattern>(), Ok(pattern.clone()));
+            assert_eq!(pattern.to_emoji().parse::<Pattern>(), Ok(pattern));
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use crate::words::{WordLists, ENGLISH_VALID_GUESSES, ENGLISH_WORDS, VALID_GUESSES, WORDS, WORDS_FREQS};
+
+/// Everything that changes from one language of the game to another: the
+/// letters words are made of, how accented letters are typed, the bundled
+/// word lists and the usual first guess.
+#[derive(Debug, PartialEq)]
+pub struct Language {
+    /// Name used by the `--language` flag, e.g. "italian".
+    pub name: &'static str,
+    /// Short name, also accepted by the `--language` flag, e.g. "it".
+    pub code: &'static str,
+    /// Letters the words are made of, in lowercase.
+    pub alphabet: &'static str,
+    /// Accented letters and the letter they stand for in the word lists.
+    pub accents: &'static [(char, char)],
+    /// Characters dropped from words, such as the apostrophe written for an
+    /// accent ("perche'") or an elision.
+    pub dropped: &'static [char],
+    /// Best first guess against the bundled answers, suggested until the
+    /// ranking of the openers is ready.
+    pub opener: &'static str,
+    answers: &'static [&'static str],
+    guesses: &'static [&'static str],
+    frequencies: &'static [(&'static str, f64)],
+}
+
+/// Italian, as played on the Italian Wordle. Loanwords bring the letters
+/// outside the Italian alphabet (j, k, w, x, y) into the lists.
+pub const ITALIAN: Language = Language {
+    name: "italian",
+    code: "it",
+    alphabet: "abcdefghijklmnopqrstuvwxyz",
+    accents: &[
+        ('à', 'a'),
+        ('á', 'a'),
+        ('è', 'e'),
+        ('é', 'e'),
+        ('ì', 'i'),
+        ('í', 'i'),
+        ('î', 'i'),
+        ('ò', 'o'),
+        ('ó', 'o'),
+        ('ù', 'u'),
+        ('ú', 'u'),
+    ],
+    dropped: &['\'', '’', '`'],
+    opener: "seria",
+    answers: WORDS,
+    guesses: VALID_GUESSES,
+    frequencies: &WORDS_FREQS,
+};
+
+/// English. The bundled lists are common five-letter words without
+/// frequencies, so every answer is considered equally likely.
+pub const ENGLISH: Language = Language {
+    name: "english",
+    code: "en",
+    alphabet: "abcdefghijklmnopqrstuvwxyz",
+    accents: &[],
+    dropped: &['\'', '’'],
+    opener: "soare",
+    answers: ENGLISH_WORDS,
+    guesses: ENGLISH_VALID_GUESSES,
+    frequencies: &[],
+};
+
+/// Every bundled language, the default one first.
+pub const LANGUAGES: [&Language; 2] = [&ITALIAN, &ENGLISH];
+
+impl Language {
+    /// Finds a language by name or code.
+    pub fn by_name(name: &str) -> Option<&'static Language> {
+        let name = name.to_lowercase();
+        LANGUAGES.into_iter().find(|language| language.name == name || language.code == name)
+    }
+
+    /// The next language in `LANGUAGES`, wrapping around.
+    pub fn next(&self) -> &'static Language {
+        let index = LANGUAGES.iter().position(|language| language.code == self.code).unwrap_or(0);
+        LANGUAGES[(index + 1) % LANGUAGES.len()]
+    }
+
+    /// The bundled word lists of the language.
+    pub fn word_lists(&self) -> WordLists {
+        WordLists {
+            answers: self.answers.iter().map(|&s| s.to_string()).collect(),
+            guesses: self.guesses.iter().map(|&s| s.to_string()).collect(),
+            frequencies: self.frequencies.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+        }
+    }
+
+    /// Returns true if `letter` (in lowercase) is in the alphabet of the language.
+    pub fn is_letter(&self, letter: char) -> bool {
+        self.alphabet.contains(letter)
+    }
+
+    /// Replaces an accented letter (in lowercase) with the one used in the
+    /// word lists, other characters are returned as they are.
+    pub fn fold(&self, letter: char) -> char {
+        self.accents
+            .iter()
+            .find(|(accented, _)| *accented == letter)
+            .map_or(letter, |&(_, plain)| plain)
+    }
+
+    /// Turns a typed or read character into a letter of the word lists:
+    /// lowercased and without its accent. Returns `Ok(None)` for characters
+    /// that are dropped, and the character itself as error if it is not part
+    /// of the alphabet.
+    pub fn normalize_char(&self, c: char) -> Result<Option<char>, char> {
+        // Accents typed as a separate combining mark follow their letter
+        if self.dropped.contains(&c) || ('\u{300}'..='\u{36f}').contains(&c) {
+            return Ok(None);
+        }
+        let letter = self.fold(c.to_lowercase().next().unwrap_or(c));
+        if self.is_letter(letter) {
+            Ok(Some(letter))
+        } else {
+            Err(c)
+        }
+    }
+
+    /// Normalizes every character of a word, see `normalize_char`.
+    pub fn normalize_word(&self, word: &str) -> Result<String, String> {
+        let mut normalized = String::with_capacity(word.len());
+        for c in word.trim().chars() {
+            match self.normalize_char(c) {
+                Ok(Some(letter)) => normalized.push(letter),
+                Ok(None) => {}
+                Err(c) => {
+                    return Err(format!("'{}' contains '{}', which is not a letter in {}", word.trim(), c, self.name));
+                }
+            }
+        }
+        Ok(normalized)
+    }
+
+    /// Name of the file caching some data computed for the language,
+    /// e.g. `patterns-it.cache` for the pattern table of Italian.
+    pub fn cache_file(&self, kind: &str) -> String {
+        format!("{}-{}.cache", kind, self.code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accents_are_folded_and_letters_lowercased() {
+        assert_eq!(ITALIAN.normalize_char('È'), Ok(Some('e')));
+        assert_eq!(ITALIAN.normalize_char('ù'), Ok(Some('u')));
+        assert_eq!(ITALIAN.normalize_char('K'), Ok(Some('k')));
+        assert_eq!(ITALIAN.normalize_word(" Perché "), Ok("perche".to_string()));
+    }
+
+    #[test]
+    fn apostrophes_and_combining_marks_are_dropped() {
+        assert_eq!(ITALIAN.normalize_char('\''), Ok(None));
+        assert_eq!(ITALIAN.normalize_char('\u{301}'), Ok(None));
+        assert_eq!(ITALIAN.normalize_word("perche'"), Ok("perche".to_string()));
+        assert_eq!(ITALIAN.normalize_word("l’alba"), Ok("lalba".to_string()));
+        assert_eq!(ITALIAN.normalize_word("perche\u{301}"), Ok("perche".to_string()));
+    }
+
+    #[test]
+    fn characters_outside_the_alphabet_are_errors() {
+        assert_eq!(ITALIAN.normalize_char('1'), Err('1'));
+        assert_eq!(ITALIAN.normalize_char('-'), Err('-'));
+        // English has no accented letters to fold
+        assert_eq!(ENGLISH.normalize_char('é'), Err('é'));
+        assert!(ITALIAN.normalize_word("cas4").is_err());
+    }
+}
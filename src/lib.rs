@@ -0,0 +1,39 @@
+//! Wordle solver based on information theory.
+//!
+//! A `Solver` keeps the history of guesses and feedback, narrows down the
+//! candidate answers and ranks the next guesses with a `Strategy`:
+//!
+//! ```no_run
+//! use parole_bot::{Pattern, Solver};
+//!
+//! let mut solver = Solver::new();
+//! let feedback: Pattern = "RRGRY".parse().unwrap();
+//! let suggestions = solver.get_next_possible_words("seria", &feedback).unwrap();
+//! println!("next guess: {}", suggestions[0].word);
+//! ```
+//!
+//! Both the `tui-app` and the `simulations` binaries are built on this crate.
+
+pub mod config;
+pub mod conflict;
+pub mod constraints;
+pub mod error;
+pub mod feedback;
+pub mod language;
+pub mod loader;
+pub mod logging;
+pub mod openers;
+pub mod parallel;
+pub mod patterns;
+pub mod solver;
+pub mod strategy;
+pub mod words;
+
+pub use config::{GameConfig, SolverConfig};
+pub use error::SolverError;
+pub use feedback::{feedback_code, feedback_pattern, Pattern, PatternCode, PatternError, TileState};
+pub use language::Language;
+pub use patterns::PatternTable;
+pub use solver::{FilterMode, GuessPool, Lookahead, ScoredGuess, Solver};
+pub use strategy::{GuessStats, Normalization, Strategy};
+pub use words::WordLists;
@@ -0,0 +1,312 @@
+//! Reads word lists and word frequencies from files, so the solver can play
+//! with other dictionaries than the bundled ones.
+//!
+//! Three formats are supported, chosen by the file extension:
+//! - plain text (any other extension): one word per line, optionally followed
+//!   by its frequency, lines starting with `#` are comments;
+//! - CSV (`.csv`): `word,frequency` records, the first one may be a header;
+//! - JSON (`.json`): an array of words, an array of `[word, frequency]` pairs
+//!   or an object mapping each word to its frequency.
+//!
+//! Words are normalized like typed ones (see `Language::normalize_word`):
+//! lowercased, with their accents folded and their apostrophes dropped. They
+//! must only contain letters of the language and are kept once, in the order
+//! they first appear.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value as Json;
+
+use crate::error::SolverError;
+use crate::feedback::MAX_WORD_LENGTH;
+use crate::language::Language;
+
+/// How a word list file is written.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ListFormat {
+    Text,
+    Csv,
+    Json,
+}
+
+impl ListFormat {
+    /// Guesses the format from the file extension, plain text by default.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()).map(str::to_ascii_lowercase).as_deref() {
+            Some("csv") => ListFormat::Csv,
+            Some("json") => ListFormat::Json,
+            _ => ListFormat::Text,
+        }
+    }
+}
+
+// Number of words of another length listed when a list mixes several lengths
+const MIXED_LENGTH_EXAMPLES: usize = 5;
+
+// A word read from a file, with its frequency if the file gives one, and the
+// line it comes from (unknown for JSON)
+struct Entry {
+    word: String,
+    frequency: Option<f64>,
+    line: Option<usize>,
+}
+
+/// Reads a list of words of `language`, any frequency in the file is ignored.
+pub fn load_words(path: &Path, language: &Language) -> Result<Vec<String>, SolverError> {
+    let mut seen = HashSet::new();
+    Ok(read_entries(path, language)?
+        .into_iter()
+        .filter(|entry| seen.insert(entry.word.clone()))
+        .map(|entry| entry.word)
+        .collect())
+}
+
+/// Reads the length shared by all the words of a list. A list mixing several
+/// lengths is rejected, naming the lines whose words differ from the first one,
+/// unless a length is picked explicitly (see `GameConfig::word_length`).
+pub fn word_length(path: &Path, language: &Language) -> Result<Option<usize>, SolverError> {
+    let entries = read_entries(path, language)?;
+    let Some(first) = entries.first() else {
+        return Ok(None);
+    };
+    let length = first.word.chars().count();
+    let others: Vec<&Entry> = entries.iter().filter(|entry| entry.word.chars().count() != length).collect();
+    let Some(other) = others.first() else {
+        return Ok(Some(length));
+    };
+    let words: Vec<String> = others
+        .iter()
+        .take(MIXED_LENGTH_EXAMPLES)
+        .map(|entry| match entry.line {
+            Some(line) => format!("'{}' (line {})", entry.word, line),
+            None => format!("'{}'", entry.word),
+        })
+        .collect();
+    let more = match others.len().saturating_sub(MIXED_LENGTH_EXAMPLES) {
+        0 => String::new(),
+        more => format!(" and {} more", more),
+    };
+    Err(invalid(
+        path,
+        other.line,
+        format!(
+            "words of different lengths: '{}' has {} letters but not {}{}, pick one length with --word-length",
+            first.word,
+            length,
+            words.join(", "),
+            more
+        ),
+    ))
+}
+
+/// Reads the frequency of each word. Every word must come with a frequency,
+/// which must be a non-negative number; the first one given for a word is kept.
+pub fn load_frequencies(path: &Path, language: &Language) -> Result<HashMap<String, f64>, SolverError> {
+    let mut frequencies = HashMap::new();
+    for entry in read_entries(path, language)? {
+        let Some(frequency) = entry.frequency else {
+            return Err(invalid(path, entry.line, format!("'{}' has no frequency", entry.word)));
+        };
+        frequencies.entry(entry.word).or_insert(frequency);
+    }
+    Ok(frequencies)
+}
+
+/// Normalizes a word read from a file, checking that it could be played:
+/// letters of `language` only, and not longer than `MAX_WORD_LENGTH`.
+pub fn normalize_word(word: &str, language: &Language) -> Result<String, String> {
+    let word = language.normalize_word(word)?;
+    if word.is_empty() {
+        return Err("empty word".to_string());
+    }
+    let length = word.chars().count();
+    if length > MAX_WORD_LENGTH {
+        return Err(format!("'{}' has {} letters, at most {} are supported", word, length, MAX_WORD_LENGTH));
+    }
+    Ok(word)
+}
+
+fn read_entries(path: &Path, language: &Language) -> Result<Vec<Entry>, SolverError> {
+    // Keep the path in the error, there may be several files to read
+    let content = fs::read_to_string(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("cannot read {}: {}", path.display(), e)))?;
+    match ListFormat::from_path(path) {
+        ListFormat::Text => read_text(path, &content, language),
+        ListFormat::Csv => read_csv(path, &content, language),
+        ListFormat::Json => read_json(path, &content, language),
+    }
+}
+
+fn read_text(path: &Path, content: &str, language: &Language) -> Result<Vec<Entry>, SolverError> {
+    let mut entries = Vec::new();
+    for (number, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        // The frequency, if any, follows the word after spaces or a comma
+        let mut fields = line.split(|c: char| c.is_whitespace() || c == ',').filter(|f| !f.is_empty());
+        let word = fields.next().unwrap_or_default();
+        let frequency = fields.next();
+        entries.push(entry(language, Some(number + 1), word, frequency).map_err(|reason| invalid(path, Some(number + 1), reason))?);
+    }
+    Ok(entries)
+}
+
+fn read_csv(path: &Path, content: &str, language: &Language) -> Result<Vec<Entry>, SolverError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .comment(Some(b'#'))
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes());
+
+    let mut entries = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        let record = record.map_err(|e| invalid(path, e.position().map(|p| p.line() as usize), e.to_string()))?;
+        let line = record.position().map_or(index + 1, |p| p.line() as usize);
+        let word = record.get(0).unwrap_or_default();
+        let frequency = record.get(1).filter(|f| !f.is_empty());
+        // A first record whose frequency is not a number is a header, e.g. "word,frequency"
+        if index == 0 && frequency.is_some_and(|f| f.parse::<f64>().is_err()) {
+            continue;
+        }
+        entries.push(entry(language, Some(line), word, frequency).map_err(|reason| invalid(path, Some(line), reason))?);
+    }
+    Ok(entries)
+}
+
+fn read_json(path: &Path, content: &str, language: &Language) -> Result<Vec<Entry>, SolverError> {
+    let json: Json = serde_json::from_str(content).map_err(|e| invalid(path, Some(e.line()), e.to_string()))?;
+
+    // Entries are reported by their position in the array or object
+    let invalid_entry = |index: usize, reason: String| invalid(path, None, format!("entry {}: {}", index + 1, reason));
+    let mut entries = Vec::new();
+    match json {
+        Json::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                let (word, frequency) = match item {
+                    Json::String(word) => (word.as_str(), None),
+                    Json::Array(pair) if pair.len() == 2 && pair[0].is_string() => {
+                        (pair[0].as_str().unwrap_or_default(), Some(&pair[1]))
+                    }
+                    _ => {
+                        let reason = "expected a word or a [word, frequency] pair".to_string();
+                        return Err(invalid_entry(index, reason));
+                    }
+                };
+                let frequency = frequency.map(Json::to_string);
+                entries.push(entry(language, None, word, frequency.as_deref()).map_err(|r| invalid_entry(index, r))?);
+            }
+        }
+        Json::Object(map) => {
+            for (index, (word, frequency)) in map.iter().enumerate() {
+                let frequency = frequency.to_string();
+                entries.push(entry(language, None, word, Some(&frequency)).map_err(|r| invalid_entry(index, r))?);
+            }
+        }
+        _ => {
+            let reason = "expected an array of words or an object of frequencies".to_string();
+            return Err(invalid(path, None, reason));
+        }
+    }
+    Ok(entries)
+}
+
+fn entry(language: &Language, line: Option<usize>, word: &str, frequency: Option<&str>) -> Result<Entry, String> {
+    let word = normalize_word(word, language)?;
+    let frequency = match frequency {
+        Some(value) => Some(
+            value
+                .parse::<f64>()
+                .ok()
+                .filter(|f| f.is_finite() && *f >= 0.0)
+                .ok_or_else(|| format!("invalid frequency '{}' for '{}'", value, word))?,
+        ),
+        None => None,
+    };
+    Ok(Entry { word, frequency, line })
+}
+
+fn invalid(path: &Path, line: Option<usize>, reason: String) -> SolverError {
+    SolverError::InvalidWordList { path: PathBuf::from(path), line, reason }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::ITALIAN;
+
+    // Writes a word list to a file of the temporary directory, named after the test
+    fn write_list(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("parole-bot-{}-{}", std::process::id(), name));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    // Line and reason of an `InvalidWordList` error
+    fn invalid_line(result: Result<impl std::fmt::Debug, SolverError>) -> (Option<usize>, String) {
+        match result {
+            Err(SolverError::InvalidWordList { line, reason, .. }) => (line, reason),
+            other => panic!("expected InvalidWordList, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn text_lists_skip_comments_and_keep_the_first_occurrence() {
+        let path = write_list("text.txt", "# answers\nCasa 3\n\nsería,2\ncasa 5\nperche' 1\n");
+        assert_eq!(load_words(&path, &ITALIAN).unwrap(), ["casa", "seria", "perche"]);
+        let frequencies = load_frequencies(&path, &ITALIAN).unwrap();
+        assert_eq!(frequencies["casa"], 3.0);
+        assert_eq!(frequencies["seria"], 2.0);
+    }
+
+    #[test]
+    fn csv_header_is_skipped() {
+        let path = write_list("header.csv", "word,frequency\ncasa,3\nmare,1.5\n");
+        assert_eq!(load_words(&path, &ITALIAN).unwrap(), ["casa", "mare"]);
+        assert_eq!(load_frequencies(&path, &ITALIAN).unwrap()["mare"], 1.5);
+    }
+
+    #[test]
+    fn json_lists_keep_their_order() {
+        let path = write_list("words.json", r#"["mare", ["casa", 2], "mare"]"#);
+        assert_eq!(load_words(&path, &ITALIAN).unwrap(), ["mare", "casa"]);
+
+        let path = write_list("freqs.json", r#"{"zorro": 1, "abaco": 2, "casa": 3}"#);
+        assert_eq!(load_words(&path, &ITALIAN).unwrap(), ["zorro", "abaco", "casa"]);
+        let (line, reason) = invalid_line(word_length(&path, &ITALIAN));
+        assert_eq!(line, None);
+        assert!(reason.starts_with("words of different lengths: 'zorro' has 5 letters but not 'casa'"), "{}", reason);
+    }
+
+    #[test]
+    fn invalid_entries_are_reported_with_their_line() {
+        let path = write_list("negative.txt", "casa 1\nmare -1\n");
+        let (line, reason) = invalid_line(load_frequencies(&path, &ITALIAN));
+        assert_eq!(line, Some(2));
+        assert_eq!(reason, "invalid frequency '-1' for 'mare'");
+
+        let path = write_list("missing.txt", "casa 1\nmare\n");
+        let (line, reason) = invalid_line(load_frequencies(&path, &ITALIAN));
+        assert_eq!(line, Some(2));
+        assert_eq!(reason, "'mare' has no frequency");
+
+        let path = write_list("digit.txt", "casa\nm4re\n");
+        assert_eq!(invalid_line(load_words(&path, &ITALIAN)).0, Some(2));
+    }
+
+    #[test]
+    fn mixed_lengths_name_the_other_lines() {
+        let path = write_list("mixed.txt", "abc\ncasa\ncasae\nfoo\n");
+        let (line, reason) = invalid_line(word_length(&path, &ITALIAN));
+        assert_eq!(line, Some(2));
+        assert!(reason.contains("'abc' has 3 letters but not 'casa' (line 2), 'casae' (line 3),"), "{}", reason);
+
+        let path = write_list("single.txt", "casa\nmare\n");
+        assert_eq!(word_length(&path, &ITALIAN).unwrap(), Some(4));
+    }
+}
@@ -0,0 +1,224 @@
+//! Leveled logging of the solver, off by default.
+//!
+//! Events are written one per line to a file, either as `key=value` text or as
+//! JSON objects (JSON lines). The solver logs a summary of every round at the
+//! `info` level and the changes to its history at the `debug` level.
+
+use std::fmt::Write as _;
+use std::fs::{File, OpenOptions};
+use std::io::{self, LineWriter, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Default log file, used when logging is enabled without a path.
+pub const DEFAULT_LOG_PATH: &str = "parole-bot.log";
+
+/// How important an event is. Enabling a level also enables the ones before it.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 5] = [LogLevel::Off, LogLevel::Error, LogLevel::Warn, LogLevel::Info, LogLevel::Debug];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogLevel::Off => "off",
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|level| level.name() == name)
+    }
+}
+
+/// How events are written.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum LogFormat {
+    /// `<time> <level> <event> key=value ...`
+    Text,
+    /// One JSON object per line.
+    Json,
+}
+
+impl LogFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogFormat::Text => "text",
+            LogFormat::Json => "json",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        [LogFormat::Text, LogFormat::Json].into_iter().find(|format| format.name() == name)
+    }
+}
+
+/// Logging settings, set from the `--log-level`, `--log-format` and `--log-file` flags.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogConfig {
+    pub level: LogLevel,
+    pub format: LogFormat,
+    pub path: PathBuf,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        LogConfig {
+            level: LogLevel::Off,
+            format: LogFormat::Text,
+            path: PathBuf::from(DEFAULT_LOG_PATH),
+        }
+    }
+}
+
+impl LogConfig {
+    /// Applies a command line flag, returning `Ok(false)` if the flag is not a logging one.
+    pub fn set_flag(&mut self, flag: &str, value: &str) -> Result<bool, String> {
+        match flag {
+            "--log-level" => {
+                self.level = LogLevel::from_name(value).ok_or_else(|| {
+                    format!("unknown log level '{}', expected off, error, warn, info or debug", value)
+                })?;
+            }
+            "--log-format" => {
+                self.format = LogFormat::from_name(value)
+                    .ok_or_else(|| format!("unknown log format '{}', expected text or json", value))?;
+            }
+            "--log-file" => self.path = PathBuf::from(value),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+/// A value attached to an event.
+#[derive(Clone, Copy, Debug)]
+pub enum Value<'a> {
+    Str(&'a str),
+    Count(usize),
+    Number(f64),
+}
+
+impl<'a> From<&'a str> for Value<'a> {
+    fn from(value: &'a str) -> Self {
+        Value::Str(value)
+    }
+}
+
+impl<'a> From<&'a String> for Value<'a> {
+    fn from(value: &'a String) -> Self {
+        Value::Str(value)
+    }
+}
+
+impl From<usize> for Value<'_> {
+    fn from(value: usize) -> Self {
+        Value::Count(value)
+    }
+}
+
+impl From<f64> for Value<'_> {
+    fn from(value: f64) -> Self {
+        Value::Number(value)
+    }
+}
+
+struct Sink {
+    format: LogFormat,
+    writer: LineWriter<File>,
+}
+
+// The level is checked on every call, so it is kept apart from the sink
+static LEVEL: AtomicU8 = AtomicU8::new(LogLevel::Off as u8);
+static SINK: Mutex<Option<Sink>> = Mutex::new(None);
+
+/// Starts (or stops, with `LogLevel::Off`) logging with the given settings.
+/// Events are appended to the log file.
+pub fn init(config: &LogConfig) -> io::Result<()> {
+    let sink = if config.level == LogLevel::Off {
+        None
+    } else {
+        let file = OpenOptions::new().create(true).append(true).open(&config.path)?;
+        Some(Sink { format: config.format, writer: LineWriter::new(file) })
+    };
+    *SINK.lock().unwrap_or_else(|e| e.into_inner()) = sink;
+    LEVEL.store(config.level as u8, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Returns true if events of the given level are written, so callers can skip
+/// computing what they would log.
+pub fn enabled(level: LogLevel) -> bool {
+    level != LogLevel::Off && level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+/// Writes an event with its fields, if its level is enabled.
+/// Logging is best effort: write errors are ignored.
+pub fn log(level: LogLevel, event: &str, fields: &[(&str, Value)]) {
+    if !enabled(level) {
+        return;
+    }
+    let mut sink = SINK.lock().unwrap_or_else(|e| e.into_inner());
+    let Some(sink) = sink.as_mut() else {
+        return;
+    };
+
+    let time = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs_f64()).unwrap_or(0.0);
+    let mut line = String::new();
+    match sink.format {
+        LogFormat::Text => {
+            let _ = write!(line, "{:.3} {} {}", time, level.name().to_uppercase(), event);
+            for (key, value) in fields {
+                let _ = match value {
+                    Value::Str(s) => write!(line, " {}={}", key, s),
+                    Value::Count(n) => write!(line, " {}={}", key, n),
+                    Value::Number(x) => write!(line, " {}={:.4}", key, x),
+                };
+            }
+        }
+        LogFormat::Json => {
+            let _ = write!(line, "{{\"ts\":{:.3},\"level\":\"{}\",\"event\":\"{}\"", time, level.name(), escape(event));
+            for (key, value) in fields {
+                let _ = match value {
+                    Value::Str(s) => write!(line, ",\"{}\":\"{}\"", escape(key), escape(s)),
+                    Value::Count(n) => write!(line, ",\"{}\":{}", escape(key), n),
+                    Value::Number(x) if x.is_finite() => write!(line, ",\"{}\":{}", escape(key), x),
+                    Value::Number(_) => write!(line, ",\"{}\":null", escape(key)),
+                };
+            }
+            line.push('}');
+        }
+    }
+    let _ = writeln!(sink.writer, "{}", line);
+}
+
+// Escapes a string for a JSON string literal
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -1,6 +1,6 @@
-use std::{error::Error, io, panic, sync::{Arc, atomic::{AtomicBool, Ordering}}};
+use std::{error::Error, io, panic, path::{Path, PathBuf}, sync::{Arc, atomic::{AtomicBool, Ordering}}};
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -9,15 +9,41 @@ use ratatui::{
     Terminal,
 };
 
+use parole_bot::logging::{self, LogConfig};
+use parole_bot::{GameConfig, SolverConfig};
+
 mod app;
 mod ui;
-pub mod solver;
 use crate::{
     app::{App, CurrentScreen, CurrentlyEditing},
     ui::ui,
 };
 
+// Settings file loaded at startup if present, and written from the settings panel
+const DEF_CONFIG_PATH: &str = "parole-bot.conf";
+
 fn main() -> Result<(), Box<dyn Error>> {
+    // Parse the settings before taking over the terminal, so errors can be printed
+    let (config, config_path, log_config, game) = match parse_args(std::env::args().collect()) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            eprintln!("Usage: tui-app [--config FILE] [-s/--strategy NAME] [--entropy-weight W] [--frequency-weight W] [--normalization max|min-max|none] [--bits-per-guess B] [--language italian|english] [--word-length N] [--max-attempts N] [--answers FILE] [--guesses FILE] [--freqs FILE] [--log-level off|error|warn|info|debug] [--log-format text|json] [--log-file FILE]");
+            std::process::exit(1);
+        }
+    };
+    let lists = match game.word_lists() {
+        Ok(lists) => lists,
+        Err(err) => {
+            eprintln!("Error: {}", err);
+            std::process::exit(1);
+        }
+    };
+    if let Err(err) = logging::init(&log_config) {
+        eprintln!("Error: cannot open the log file {}: {}", log_config.path.display(), err);
+        std::process::exit(1);
+    }
+
     // Setup panic hook for proper cleanup
     let original_hook = panic::take_hook();
     panic::set_hook(Box::new(move |panic_info| {
@@ -39,7 +65,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let running = Arc::new(AtomicBool::new(true));
 
     // Create app and run it
-    let mut app = App::new();
+    let mut app = App::new(config, config_path, game, lists);
     let res = run_app(&mut terminal, &mut app, running.clone());
 
     // Signal threads to stop
@@ -55,6 +81,39 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+// Reads the settings file (the default one only if it exists), then applies the flags on top.
+// The logging and game flags are returned apart.
+fn parse_args(args: Vec<String>) -> Result<(SolverConfig, PathBuf, LogConfig, GameConfig), String> {
+    let mut config_path = PathBuf::from(DEF_CONFIG_PATH);
+    let mut explicit_config = false;
+    let mut flags: Vec<(&'static str, String)> = Vec::new();
+    let mut log_config = LogConfig::default();
+    let mut game = GameConfig::default();
+
+    let mut i = 1; // Skip program name at args[0]
+    while i < args.len() {
+        let value = args.get(i + 1).ok_or_else(|| format!("missing value after {}", args[i]))?;
+        if args[i] == "--config" {
+            config_path = PathBuf::from(value);
+            explicit_config = true;
+        } else if let Some(key) = SolverConfig::flag_key(&args[i]) {
+            flags.push((key, value.clone()));
+        } else if !log_config.set_flag(&args[i], value)? && !game.set_flag(&args[i], value)? {
+            return Err(format!("unknown argument {}", args[i]));
+        }
+        i += 2;
+    }
+
+    let mut config = SolverConfig::default();
+    if explicit_config || Path::new(&config_path).exists() {
+        config.load(&config_path)?;
+    }
+    for (key, value) in flags {
+        config.set(key, &value)?;
+    }
+    Ok((config, config_path, log_config, game))
+}
+
 fn cleanup_terminal() -> Result<(), Box<dyn Error>> {
     disable_raw_mode()?;
     execute!(
@@ -76,7 +135,18 @@ fn run_app<B: Backend>(
 
         // Non-blocking event check
         if event::poll(std::time::Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
+            let event = event::read()?;
+            // Clicking a suggestion picks it, unless a panel is open
+            if let Event::Mouse(mouse) = event {
+                let on_grid = matches!(
+                    app.current_screen,
+                    CurrentScreen::Main | CurrentScreen::EditingTileChar | CurrentScreen::EditingTileColor
+                );
+                if on_grid && mouse.kind == MouseEventKind::Down(MouseButton::Left) {
+                    app.click(mouse.column, mouse.row);
+                }
+            }
+            if let Event::Key(key) = event {
                 if key.kind == KeyEventKind::Release {
                     // Skip events that are not KeyEventKind::Press
                     continue;
@@ -87,6 +157,51 @@ fn run_app<B: Backend>(
                         KeyCode::Char('q') => {
                             app.current_screen = CurrentScreen::Exiting;
                         }
+                        KeyCode::Char('d') => {
+                            app.toggle_guess_pool();
+                        }
+                        KeyCode::Char('h') => {
+                            app.toggle_hard_mode();
+                        }
+                        KeyCode::Char('l') => {
+                            app.toggle_lookahead();
+                        }
+                        KeyCode::Char('o') => {
+                            app.current_screen = CurrentScreen::Settings;
+                        }
+                        KeyCode::Char('u') => {
+                            app.undo();
+                        }
+                        KeyCode::Char('r') => {
+                            app.redo();
+                        }
+                        KeyCode::Char('g') => {
+                            app.next_language();
+                        }
+                        KeyCode::Char('s') => {
+                            app.next_suggestion_sort();
+                        }
+                        KeyCode::Char('c') => {
+                            app.current_screen = CurrentScreen::Candidates;
+                        }
+                        KeyCode::Up => {
+                            app.scroll_suggestions(-1);
+                        }
+                        KeyCode::Down => {
+                            app.scroll_suggestions(1);
+                        }
+                        KeyCode::PageUp => {
+                            app.scroll_suggestions(-10);
+                        }
+                        KeyCode::PageDown => {
+                            app.scroll_suggestions(10);
+                        }
+                        KeyCode::Enter => {
+                            app.pick_selected_suggestion();
+                        }
+                        KeyCode::Char(c @ '1'..='9') => {
+                            app.pick_suggestion(c as usize - '1' as usize);
+                        }
                         
                         KeyCode::Tab => {
                             app.current_screen = CurrentScreen::EditingTileChar;
@@ -118,9 +233,7 @@ fn run_app<B: Backend>(
                             app.go_next_col();
                         }
                         KeyCode::Char(c) => {
-                            for upper_c in c.to_uppercase() {
-                                app.insert_char(upper_c);
-                            }
+                            app.type_char(c);
                         }
                         KeyCode::Tab => {
                             app.current_screen = CurrentScreen::EditingTileColor;
@@ -151,17 +264,62 @@ fn run_app<B: Backend>(
                         KeyCode::Char('p') => {
                             app.go_prev_color();
                         }
+                        KeyCode::Char('f') => {
+                            app.use_did_you_mean();
+                        }
                         KeyCode::Tab => {
                             app.current_screen = CurrentScreen::EditingTileChar;
                             app.currently_editing = Some(CurrentlyEditing::TileChar);
                         }
-                        KeyCode::Enter => {
-                            app.calculate_next_possible_word();
+                        // Stay on the colors if the row was rejected, so it can be fixed
+                        KeyCode::Enter if app.calculate_next_possible_word() => {
                             app.current_screen = CurrentScreen::EditingTileChar;
                             app.currently_editing = None;
                         }
                         _ => {}
                     },
+                    CurrentScreen::Settings => match key.code {
+                        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('o') => {
+                            app.current_screen = CurrentScreen::Main;
+                        }
+                        KeyCode::Up => {
+                            app.go_prev_setting();
+                        }
+                        KeyCode::Down => {
+                            app.go_next_setting();
+                        }
+                        KeyCode::Left => {
+                            app.adjust_setting(-1);
+                        }
+                        KeyCode::Right => {
+                            app.adjust_setting(1);
+                        }
+                        KeyCode::Char('w') => {
+                            app.save_config();
+                        }
+                        _ => {}
+                    },
+                    CurrentScreen::Candidates => match key.code {
+                        KeyCode::Esc | KeyCode::Enter => {
+                            app.current_screen = CurrentScreen::Main;
+                        }
+                        KeyCode::Tab => {
+                            app.toggle_candidate_sort();
+                        }
+                        KeyCode::Left | KeyCode::PageUp => {
+                            app.turn_candidate_page(-1);
+                        }
+                        KeyCode::Right | KeyCode::PageDown => {
+                            app.turn_candidate_page(1);
+                        }
+                        KeyCode::Backspace => {
+                            app.search_backspace();
+                        }
+                        KeyCode::Char(c) => {
+                            app.search_char(c);
+                        }
+                        _ => {}
+                    },
                     CurrentScreen::Exiting => {
                         running.store(false, Ordering::SeqCst);
                         return Ok(true);
@@ -0,0 +1,38 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+/// Reads a ranking of opening words written by `save_openers`.
+/// Returns `Ok(None)` if the file was written for different word lists.
+pub fn load_openers(path: &Path, hash: u64) -> io::Result<Option<Vec<(String, f64)>>> {
+    let mut lines = BufReader::new(File::open(path)?).lines();
+
+    // The first line holds the hash of the word lists the ranking was computed for
+    let header = lines.next().transpose()?.unwrap_or_default();
+    if u64::from_str_radix(header.trim(), 16).ok() != Some(hash) {
+        return Ok(None);
+    }
+
+    let mut openers = Vec::new();
+    for line in lines {
+        let line = line?;
+        let Some((word, entropy)) = line.split_once(',') else {
+            return Ok(None);
+        };
+        let Ok(entropy) = entropy.trim().parse::<f64>() else {
+            return Ok(None);
+        };
+        openers.push((word.trim().to_string(), entropy));
+    }
+    Ok(Some(openers))
+}
+
+/// Writes a ranking of opening words to `path`, keyed by the hash of the word lists.
+pub fn save_openers(path: &Path, hash: u64, openers: &[(String, f64)]) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    writeln!(writer, "{:016x}", hash)?;
+    for (word, entropy) in openers {
+        writeln!(writer, "{},{:.6}", word, entropy)?;
+    }
+    writer.flush()
+}
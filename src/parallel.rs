@@ -0,0 +1,58 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+// Number of chunks per worker, more chunks balance the load better
+const CHUNKS_PER_WORKER: usize = 4;
+
+/// Resolves a configured thread count, where 0 means one thread per available core.
+pub fn resolve_threads(threads: usize) -> usize {
+    if threads > 0 {
+        threads
+    } else {
+        thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+    }
+}
+
+/// Applies `f` to every item using up to `threads` workers (0 = all cores) and
+/// returns the results in the same order as `items`.
+/// Workers repeatedly take the next chunk of items from a shared counter, so a
+/// worker that finishes early steals the remaining work from the slower ones.
+pub fn parallel_map<T, R, F>(items: &[T], threads: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = resolve_threads(threads).min(items.len());
+    if workers <= 1 {
+        return items.iter().map(f).collect();
+    }
+    let chunk_size = items.len().div_ceil(workers * CHUNKS_PER_WORKER);
+    let chunks = items.len().div_ceil(chunk_size);
+
+    let next_chunk = AtomicUsize::new(0);
+    let results: Mutex<Vec<(usize, Vec<R>)>> = Mutex::new(Vec::with_capacity(chunks));
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                let mut done = Vec::new();
+                loop {
+                    let chunk = next_chunk.fetch_add(1, Ordering::Relaxed);
+                    if chunk >= chunks {
+                        break;
+                    }
+                    let start = chunk * chunk_size;
+                    let end = (start + chunk_size).min(items.len());
+                    done.push((chunk, items[start..end].iter().map(&f).collect()));
+                }
+                results.lock().unwrap_or_else(|e| e.into_inner()).extend(done);
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap_or_else(|e| e.into_inner());
+    results.sort_by_key(|(chunk, _)| *chunk);
+    results.into_iter().flat_map(|(_, chunk)| chunk).collect()
+}
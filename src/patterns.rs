@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use crate::feedback::{feedback_code, PatternCode};
+
+const CACHE_MAGIC: &[u8; 4] = b"PBPT";
+const CACHE_VERSION: u8 = 2;
+
+/// Stable FNV-1a hash of some word lists, used to key the on-disk caches.
+pub fn word_lists_hash(lists: &[&[String]]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut feed = |byte: u8| {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    };
+    for list in lists {
+        for word in list.iter() {
+            word.bytes().for_each(&mut feed);
+            feed(b'\n');
+        }
+        // Separate the lists so that moving a word from one to the other changes the hash
+        feed(0);
+    }
+    hash
+}
+
+/// Precomputed feedback codes for every (guess, answer) pair.
+pub struct PatternTable {
+    guess_index: HashMap<String, usize>,
+    answer_index: HashMap<String, usize>,
+    guesses_len: usize,
+    answers_len: usize,
+    hash: u64,
+    codes: Vec<PatternCode>,
+}
+
+impl PatternTable {
+    /// Scores every guess against every answer.
+    pub fn build(guesses: &[String], answers: &[String]) -> Self {
+        let mut codes = Vec::with_capacity(guesses.len() * answers.len());
+        for guess in guesses {
+            codes.extend(answers.iter().map(|answer| feedback_code(guess, answer)));
+        }
+        Self::from_codes(guesses, answers, codes)
+    }
+
+    /// Loads the table from `path` if it was built for the same word lists,
+    /// otherwise builds it and tries to write it back to `path`.
+    pub fn load_or_build(path: &Path, guesses: &[String], answers: &[String]) -> Self {
+        if let Ok(Some(table)) = Self::load(path, guesses, answers) {
+            return table;
+        }
+        let table = Self::build(guesses, answers);
+        // The cache is only an optimization, a failed write is not fatal
+        let _ = table.save(path);
+        table
+    }
+
+    /// Reads a table previously written by `save`. Returns `Ok(None)` if the file
+    /// was built for different word lists.
+    pub fn load(path: &Path, guesses: &[String], answers: &[String]) -> io::Result<Option<Self>> {
+        let mut reader = BufReader::new(File::open(path)?);
+
+        let mut header = [0u8; 4 + 1 + 8 + 4 + 4];
+        reader.read_exact(&mut header)?;
+        let hash = u64::from_le_bytes(header[5..13].try_into().unwrap());
+        let guesses_len = u32::from_le_bytes(header[13..17].try_into().unwrap()) as usize;
+        let answers_len = u32::from_le_bytes(header[17..21].try_into().unwrap()) as usize;
+
+        if &header[0..4] != CACHE_MAGIC
+            || header[4] != CACHE_VERSION
+            || hash != word_lists_hash(&[guesses, answers])
+            || guesses_len != guesses.len()
+            || answers_len != answers.len()
+        {
+            return Ok(None);
+        }
+
+        // Codes are stored as little-endian u16
+        let mut bytes = vec![0; guesses_len * answers_len * 2];
+        reader.read_exact(&mut bytes)?;
+        let codes = bytes.chunks_exact(2).map(|pair| PatternCode::from_le_bytes([pair[0], pair[1]])).collect();
+        Ok(Some(Self::from_codes(guesses, answers, codes)))
+    }
+
+    /// Writes the table to `path`, keyed by a hash of the word lists.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(CACHE_MAGIC)?;
+        writer.write_all(&[CACHE_VERSION])?;
+        writer.write_all(&self.hash.to_le_bytes())?;
+        writer.write_all(&(self.guesses_len as u32).to_le_bytes())?;
+        writer.write_all(&(self.answers_len as u32).to_le_bytes())?;
+        for code in &self.codes {
+            writer.write_all(&code.to_le_bytes())?;
+        }
+        writer.flush()
+    }
+
+    fn from_codes(guesses: &[String], answers: &[String], codes: Vec<PatternCode>) -> Self {
+        PatternTable {
+            guess_index: guesses.iter().enumerate().map(|(i, w)| (w.clone(), i)).collect(),
+            answer_index: answers.iter().enumerate().map(|(i, w)| (w.clone(), i)).collect(),
+            guesses_len: guesses.len(),
+            answers_len: answers.len(),
+            hash: word_lists_hash(&[guesses, answers]),
+            codes,
+        }
+    }
+
+    pub fn guess_id(&self, guess: &str) -> Option<usize> {
+        self.guess_index.get(guess).copied()
+    }
+
+    pub fn answer_id(&self, answer: &str) -> Option<usize> {
+        self.answer_index.get(answer).copied()
+    }
+
+    /// Feedback codes of a guess against every answer, indexed by answer id.
+    pub fn row(&self, guess_id: usize) -> &[PatternCode] {
+        &self.codes[guess_id * self.answers_len..(guess_id + 1) * self.answers_len]
+    }
+}
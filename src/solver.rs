@@ -1,81 +1,204 @@
-include!("../assets/valid_guesses.rs");
-include!("../assets/wordlist.rs");
-include!("../assets/words_freqs.rs");
-
-use std::collections::HashMap;
-use std::fs::OpenOptions;
-use std::io::Write;
-
-// Logger function to write messages to a log file
-fn log_to_file(message: &str) {
-    let mut file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open("debug.log")
-        .unwrap();
-    writeln!(file, "{}", message).unwrap();
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+use crate::conflict::{find_conflict, Conflict};
+use crate::constraints::Constraints;
+use crate::error::SolverError;
+use crate::openers::{load_openers, save_openers};
+use crate::parallel::parallel_map;
+use crate::strategy::{BlendStrategy, GuessStats, Normalization, Strategy};
+use crate::logging::{self, LogLevel};
+use crate::language::{Language, ITALIAN};
+use crate::feedback::{feedback_code, feedback_pattern, pattern_count, Pattern, PatternCode};
+use crate::patterns::{word_lists_hash, PatternTable};
+use crate::words::{edit_distance, WordLists};
+
+/// How the candidate answers are narrowed down after each guess.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum FilterMode {
+    /// Keep the words satisfying the letter constraints accumulated from the feedback.
+    Constraints,
+    /// Keep the words that would have produced every observed pattern in `previous_words`.
+    PatternConsistency,
+}
+
+/// Which words are ranked as next guesses.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GuessPool {
+    /// Only the remaining candidate answers.
+    Candidates,
+    /// The whole guess dictionary, scored against the remaining candidate answers.
+    FullDictionary,
+}
+
+/// How far ahead the guesses are evaluated.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Lookahead {
+    /// Rank each guess by its own entropy/frequency score.
+    Greedy,
+    /// Re-rank the best `top_k` guesses by the expected number of candidates left
+    /// after the guess and the best follow-up guess for each possible feedback.
+    TwoStep { top_k: usize },
+}
+
+/// Number of guesses re-ranked by the two-step lookahead by default.
+pub const DEFAULT_LOOKAHEAD_TOP_K: usize = 20;
+
+/// A ranked guess, with the numbers it was ranked by.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoredGuess {
+    pub word: String,
+    /// Expected information gain in bits.
+    pub entropy: f64,
+    /// Frequency normalized across the ranked guesses, zero for words that
+    /// cannot be the answer.
+    pub normalized_frequency: f64,
+    /// Score given by the strategy, higher is better.
+    pub score: f64,
+    /// Number of candidates left in the worst case.
+    pub largest_bucket: usize,
+    /// Expected number of candidates left after the feedback.
+    pub expected_size: f64,
+    /// Whether the guess can still be the answer.
+    pub is_candidate: bool,
 }
 
+/// Solver state is derived from the history of guesses and their feedback:
+/// the candidates and the constraints are recomputed whenever the history
+/// changes, so rows can be undone, redone or edited at any time.
 #[derive(Clone)]
 pub struct Solver {
-    pub words: Vec<String>,
-    //pub valid_guesses: Vec<String>,
+    // Answers consistent with the whole history
+    words: Vec<String>,
+    // Full list of possible answers, before any filtering
+    answers: Vec<String>,
+    valid_guesses: Vec<String>,
     pub word_length: usize,
     pub word_frequencies: HashMap<String, f64>,
-    pub previous_words: Vec<(String, String)>,
-    // Add these fields to track accumulated constraints
-    accumulated_absent_chars: Vec<(char, usize)>,
-    accumulated_present_chars: Vec<(char, usize)>,
-    accumulated_wrong_placed_chars: Vec<(char, usize)>,
+    // Guesses and their feedback, in the order they were played
+    previous_words: Vec<(String, Pattern)>,
+    // Rows removed by `undo`, the last one is restored first by `redo`
+    undone_words: Vec<(String, Pattern)>,
+    filter_mode: FilterMode,
+    pub guess_pool: GuessPool,
+    // When set, suggestions must reuse every revealed hint
+    pub hard_mode: bool,
+    pub lookahead: Lookahead,
+    // Scores the guesses, shared between clones of the solver
+    pub strategy: Arc<dyn Strategy>,
+    // How entropy and frequency are scaled before the strategy combines them
+    pub normalization: Normalization,
+    // Number of threads used to score the guesses (0 = all available cores)
+    pub threads: usize,
+    // Per-letter and per-position constraints accumulated from previous_words
+    constraints: Constraints,
+    // Precomputed feedback codes, shared between clones of the solver
+    pattern_table: Option<Arc<PatternTable>>,
+    // Language of the guesses, to lowercase them and fold their accents
+    language: &'static Language,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Solver {
+    /// Creates a solver playing with the bundled word lists.
     pub fn new() -> Self {
-        let words_copy: Vec<String> = WORDS.iter().map(|&s| s.to_string()).collect();
+        Self::with_word_lists(WordLists::embedded())
+    }
+
+    /// Creates a solver playing with the given word lists. The length of the
+    /// words is the one of the first answer, words of other lengths are dropped.
+    pub fn with_word_lists(lists: WordLists) -> Self {
+        let word_length = lists.word_length();
+        let lists = lists.with_length(word_length);
         Solver {
-            words: words_copy.clone(),
-            //valid_guesses: VALID_GUESSES.iter().map(|&s| s.to_string()).collect(),
-            word_length: 5,
-            word_frequencies: WORDS_FREQS.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+            words: lists.answers.clone(),
+            answers: lists.answers,
+            valid_guesses: lists.guesses,
+            word_length,
+            word_frequencies: lists.frequencies,
             previous_words: Vec::new(),
-            // Add these fields to track accumulated constraints
-            accumulated_absent_chars: Vec::new(),
-            accumulated_present_chars: Vec::new(),
-            accumulated_wrong_placed_chars: Vec::new(),
+            undone_words: Vec::new(),
+            filter_mode: FilterMode::Constraints,
+            guess_pool: GuessPool::Candidates,
+            hard_mode: false,
+            lookahead: Lookahead::Greedy,
+            strategy: Arc::new(BlendStrategy::default()),
+            normalization: Normalization::Max,
+            threads: 0,
+            constraints: Constraints::new(word_length),
+            pattern_table: None,
+            language: &ITALIAN,
         }
     }
 
-    /// Given a guessed word and the feedback from comparing it to a candidate answer,
-    /// returns a string pattern where:
-    /// - 'G' indicates the letter is in the correct position (green),
-    /// - 'Y' indicates the letter is present but in a wrong position (yellow),
-    /// - 'R' indicates the letter is absent (black).
-    pub fn get_feedback_pattern(&self, guess: &str, answer: &str) -> String {
-        // Convert strings to vectors of characters.
-        let mut pattern = vec!['R'; self.word_length];
-        let mut answer_chars: Vec<char> = answer.chars().collect();
-        let guess_chars: Vec<char> = guess.chars().collect();
-
-        // First pass: mark greens.
-        for i in 0..self.word_length {
-            if guess_chars[i] == answer_chars[i] {
-                pattern[i] = 'G';
-                // Mark this letter as used.
-                answer_chars[i] = '*';
-            }
-        }
-        // Second pass: mark yellows.
-        for i in 0..self.word_length {
-            if pattern[i] == 'G' {
-                continue;
-            }
-            if let Some(pos) = answer_chars.iter().position(|&c| c == guess_chars[i]) {
-                pattern[i] = 'Y';
-                // Mark the matched letter as used.
-                answer_chars[pos] = '*';
-            }
+    /// Uses a precomputed table of feedback codes for entropy calculations.
+    /// The table should cover `valid_guesses` × the initial candidate answers,
+    /// pairs missing from it are scored on the fly.
+    pub fn set_pattern_table(&mut self, table: Arc<PatternTable>) {
+        self.pattern_table = Some(table);
+    }
+
+    /// Every word that can be the answer, before any filtering.
+    pub fn answers(&self) -> &[String] {
+        &self.answers
+    }
+
+    /// Every word accepted as a guess.
+    pub fn valid_guesses(&self) -> &[String] {
+        &self.valid_guesses
+    }
+
+    /// How the candidates are filtered, see `FilterMode`.
+    pub fn filter_mode(&self) -> FilterMode {
+        self.filter_mode
+    }
+
+    /// Changes how the candidates are filtered and filters them again.
+    pub fn set_filter_mode(&mut self, filter_mode: FilterMode) {
+        self.filter_mode = filter_mode;
+        self.recompute();
+    }
+
+    /// Sets the language the guesses are normalized with, Italian by default.
+    pub fn set_language(&mut self, language: &'static Language) {
+        self.language = language;
+    }
+
+    /// Ranks every guess in the dictionary by its expected entropy against the full
+    /// answer list, i.e. as the first guess of a game. Best openers come first.
+    pub fn rank_openers(&self) -> Vec<(String, f64)> {
+        let answer_ids = self.answer_ids(&self.answers);
+        let entropies = parallel_map(&self.valid_guesses, self.threads, |guess| {
+            let pattern_counts = self.pattern_histogram(guess, &self.answers, answer_ids.as_deref());
+            entropy_of(&pattern_counts, self.answers.len())
+        });
+
+        let mut openers: Vec<(String, f64)> = self.valid_guesses.iter().cloned().zip(entropies).collect();
+        openers.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        openers
+    }
+
+    /// Same as `rank_openers`, but the ranking is cached in `path` and only
+    /// recomputed when the word lists change.
+    pub fn cached_openers(&self, path: &Path) -> Vec<(String, f64)> {
+        let hash = word_lists_hash(&[&self.valid_guesses, &self.answers]);
+        if let Ok(Some(openers)) = load_openers(path, hash) {
+            return openers;
         }
-        pattern.into_iter().collect()
+        let openers = self.rank_openers();
+        // The cache is only an optimization, a failed write is not fatal
+        let _ = save_openers(path, hash, &openers);
+        openers
+    }
+
+    /// Returns the feedback the game gives for `guess` when the answer is `answer`.
+    pub fn get_feedback_pattern(&self, guess: &str, answer: &str) -> Pattern {
+        feedback_pattern(guess, answer)
     }
 
     /// Calculates the expected information gain (entropy) of making a given guess,
@@ -84,217 +207,756 @@ impl Solver {
     /// 2. Building a distribution over these patterns.
     /// 3. Computing the entropy of that distribution.
     pub fn calculate_expected_entropy(&self, guess: &str, candidate_answers: &[String]) -> f64 {
-        let total = candidate_answers.len() as f64;
-        let mut pattern_counts: HashMap<String, usize> = HashMap::new();
+        let answer_ids = self.answer_ids(candidate_answers);
+        let pattern_counts = self.pattern_histogram(guess, candidate_answers, answer_ids.as_deref());
+        entropy_of(&pattern_counts, candidate_answers.len())
+    }
 
-        // For each candidate answer, simulate the feedback pattern.
-        for answer in candidate_answers {
-            let pattern = self.get_feedback_pattern(guess, answer);
-            *pattern_counts.entry(pattern).or_insert(0) += 1;
-        }
+    // Looks up the answers in the pattern table, if every one of them is there
+    fn answer_ids(&self, answers: &[String]) -> Option<Vec<usize>> {
+        let table = self.pattern_table.as_ref()?;
+        answers.iter().map(|answer| table.answer_id(answer)).collect()
+    }
 
-        // Compute the entropy: H = - Σ p(pattern) log₂(p(pattern))
-        let mut entropy = 0.0;
-        for &count in pattern_counts.values() {
-            let p = count as f64 / total;
-            entropy -= p * p.log2();
+    // Feedback code of the guess against each answer, read from the pattern table
+    // when it covers them and computed otherwise
+    fn feedback_codes(&self, guess: &str, answers: &[String], answer_ids: Option<&[usize]>) -> Vec<PatternCode> {
+        if let (Some(table), Some(ids)) = (&self.pattern_table, answer_ids) {
+            if let Some(guess_id) = table.guess_id(guess) {
+                let row = table.row(guess_id);
+                return ids.iter().map(|&id| row[id]).collect();
+            }
         }
-        entropy
+        answers.iter().map(|answer| feedback_code(guess, answer)).collect()
     }
 
-    /// Filters the words based on the absent characters (R), correctly placed characters (G),
-    /// and mis-placed characters (Y) as per the input feedback.
-    pub fn filter_words(
+    // Counts how many answers produce each feedback code for the given guess
+    fn pattern_histogram(
         &self,
-        words: &[String],
-        absent_chars: &Vec<(char, usize)>,
-        present_chars: &Vec<(char, usize)>,
-        wrong_placed_chars: &Vec<(char, usize)>
-    ) -> Vec<String> {
-        let filtered_words: Vec<String> = words.iter().filter(|word| {
-            // Check absent characters.
-            for (c, _) in absent_chars {
-                if word.contains(*c) {
-                    return false;
-                }
-            }
-            // Check correctly placed characters.
-            for (c, pos) in present_chars {
-                if word.chars().nth(*pos).unwrap_or(' ') != *c {
-                    return false;
-                }
-            }
-            // Check wrongly placed characters.
-            for (c, pos) in wrong_placed_chars {
-                if word.chars().nth(*pos).unwrap_or(' ') == *c || !word.contains(*c) {
-                    return false;
-                }
-            }
-            true
-        }).cloned().collect();
-
-        // Log the current word list after filtering.
-        self.print_word_list();
-
-        filtered_words
-    }
-
-    /// Returns the current word list as a log entry.
-    pub fn print_word_list(&self) {
-        let separator = " | ";
-        let word_list = self.words.join(separator);
-        log_to_file(&format!("Current word list: {}", word_list));
-    }
-
-    /// Given a guess and the feedback state (using 'R', 'G', 'Y') for each letter,
-    /// first filters the word list and then calculates the expected entropy for each candidate word.
-    /// It returns the candidate words sorted by their expected entropy (information gain) in descending order.
-    pub fn get_next_possible_words(&mut self, word: &str, color_state: &str) -> Vec<String> {
-        let mut absent_chars: Vec<(char, usize)> = Vec::new();
-        let mut present_chars: Vec<(char, usize)> = Vec::new();
-        let mut wrong_placed_chars: Vec<(char, usize)> = Vec::new();
-
-        for (i, c) in word.chars().enumerate() {
-            let state = color_state.chars().nth(i).unwrap();
-            if state == 'R' {
-                absent_chars.push((c, i));
-            } else if state == 'G' {
-                present_chars.push((c, i));
-            } else if state == 'Y' {
-                wrong_placed_chars.push((c, i));
-            }
+        guess: &str,
+        answers: &[String],
+        answer_ids: Option<&[usize]>,
+    ) -> Vec<usize> {
+        let mut counts = vec![0; pattern_count(self.word_length)];
+        for code in self.feedback_codes(guess, answers, answer_ids) {
+            counts[code as usize] += 1;
         }
+        counts
+    }
 
-        // Add constraints from current guess to accumulated constraints
-        self.update_accumulated_constraints(&absent_chars, &present_chars, &wrong_placed_chars);
+    /// Filters the words keeping only the ones that satisfy the given constraints.
+    pub fn filter_words(&self, words: &[String], constraints: &Constraints) -> Vec<String> {
+        words
+            .iter()
+            .filter(|word| constraints.matches(word))
+            .cloned()
+            .collect()
+    }
 
-        // Apply all accumulated constraints during filtering
-        let filtered_words = self.filter_words_with_all_constraints(&self.words);
-        
-        // Calculate entropy and prepare combined scoring
-        let mut word_scores: Vec<(String, f64, f64, f64)> = Vec::new(); // (word, entropy, frequency, combined_score)
-        
-        // Find max entropy and max frequency for normalization
-        let mut max_entropy: f64 = 0.0;
-        let mut max_frequency: f64 = 0.0;
-        
-        for candidate in &filtered_words {
-            let entropy = self.calculate_expected_entropy(candidate, &filtered_words);
-            let frequency = self.word_frequencies.get(candidate).copied().unwrap_or(0.0);
-            
-            max_entropy = max_entropy.max(entropy);
-            max_frequency = max_frequency.max(frequency);
-            
-            word_scores.push((candidate.to_string(), entropy, frequency, 0.0)); // Will calculate score later
-        }
-        
-        // Calculate combined scores with weights
-        let entropy_weight = 0.8;  // Prioritize entropy (information gain)
-        let frequency_weight = 0.2; // But also consider frequency
-        
-        for score in &mut word_scores {
-            // Normalize values between 0 and 1
-            let normalized_entropy = if max_entropy > 0.0 { score.1 / max_entropy } else { 0.0 };
-            let normalized_frequency = if max_frequency > 0.0 { score.2 / max_frequency } else { 0.0 };
-            
-            // Weighted combination
-            score.3 = (entropy_weight * normalized_entropy) + (frequency_weight * normalized_frequency);
+    /// Returns true if `word` is still among the candidate answers.
+    pub fn is_candidate(&self, word: &str) -> bool {
+        self.words.iter().any(|w| w == word)
+    }
+
+    /// Returns true if `word` is in the guess dictionary.
+    pub fn is_valid_guess(&self, word: &str) -> bool {
+        self.valid_guesses.iter().any(|w| w == word)
+    }
+
+    /// Words of the guess dictionary closest to `word`, at most `max_distance`
+    /// edits away (see `edit_distance`), to correct a typo. The nearest come
+    /// first, then the candidate answers and the most frequent words.
+    pub fn closest_guesses(&self, word: &str, max_distance: usize, count: usize) -> Vec<String> {
+        let mut closest: Vec<(usize, bool, f64, &String)> = self
+            .valid_guesses
+            .iter()
+            .map(|guess| (edit_distance(word, guess), guess))
+            .filter(|&(distance, _)| distance <= max_distance)
+            .map(|(distance, guess)| {
+                let frequency = self.word_frequencies.get(guess).copied().unwrap_or(0.0);
+                (distance, !self.is_candidate(guess), frequency, guess)
+            })
+            .collect();
+        closest.sort_by(|a, b| {
+            a.0.cmp(&b.0)
+                .then(a.1.cmp(&b.1))
+                .then(b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal))
+                .then(a.3.cmp(b.3))
+        });
+        closest.into_iter().take(count).map(|(_, _, _, guess)| guess.clone()).collect()
+    }
+
+    /// Given a guess and the feedback on each of its letters,
+    /// first adds them to the history and then ranks the next guesses, see `rank_guesses`.
+    pub fn get_next_possible_words(&mut self, word: &str, color_state: &Pattern) -> Result<Vec<ScoredGuess>, SolverError> {
+        // Add the current guess to the history and to the accumulated constraints
+        self.record_feedback(word, color_state)?;
+        self.rank_guesses()
+    }
+
+    /// Calculates the expected entropy of each guess in the guess pool against the
+    /// remaining candidates, and returns the scored guesses sorted by the score
+    /// given by the strategy in descending order.
+    pub fn rank_guesses(&self) -> Result<Vec<ScoredGuess>, SolverError> {
+        let filtered_words = &self.words[..];
+        if filtered_words.is_empty() {
+            return Err(SolverError::EmptyCandidateSet);
         }
+
+        // Candidates always satisfy the hard-mode rules, the rest of the dictionary may not
+        let legal_guesses: Vec<String>;
+        let guesses: &[String] = match self.guess_pool {
+            GuessPool::Candidates => filtered_words,
+            GuessPool::FullDictionary if self.hard_mode => {
+                legal_guesses = self
+                    .valid_guesses
+                    .iter()
+                    .filter(|guess| self.hard_mode_violation(guess).is_none())
+                    .cloned()
+                    .collect();
+                &legal_guesses
+            }
+            GuessPool::FullDictionary => &self.valid_guesses,
+        };
         
-        // Sort by combined score (higher is better)
-        word_scores.sort_by(|a, b| b.3.partial_cmp(&a.3).unwrap_or(std::cmp::Ordering::Equal));
+        // Look up the candidates in the pattern table once for all the guesses
+        let answer_ids = self.answer_ids(filtered_words);
+
+        // Score every guess with the selected strategy
+        let mut ranked = self.score_stats(self.compute_guess_stats(guesses, filtered_words, answer_ids.as_deref()));
         
-        // Return just the words
-        word_scores.into_iter().map(|(word, _, _, _)| word).collect()
-    }
-
-    // New method to update accumulated constraints
-    fn update_accumulated_constraints(
-        &mut self,
-        absent_chars: &Vec<(char, usize)>,
-        present_chars: &Vec<(char, usize)>,
-        wrong_placed_chars: &Vec<(char, usize)>
-    ) {
-        // Add new green (present) constraints
-        for &constraint in present_chars {
-            if !self.accumulated_present_chars.contains(&constraint) {
-                self.accumulated_present_chars.push(constraint);
+        // Sort by score (higher is better), preferring possible answers and then entropy on ties
+        ranked.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(b.is_candidate.cmp(&a.is_candidate))
+                .then(b.entropy.partial_cmp(&a.entropy).unwrap_or(std::cmp::Ordering::Equal))
+        });
+
+        let ranked = match self.lookahead {
+            Lookahead::TwoStep { top_k } if filtered_words.len() > 2 => {
+                self.rerank_two_step(ranked, top_k, filtered_words, answer_ids.as_deref())
             }
+            _ => ranked,
+        };
+
+        if let (Some((guess, pattern)), Some(best)) = (self.previous_words.last(), ranked.first()) {
+            logging::log(
+                LogLevel::Info,
+                "round",
+                &[
+                    ("round", self.previous_words.len().into()),
+                    ("guess", guess.into()),
+                    ("pattern", (&pattern.to_string()).into()),
+                    ("candidates", filtered_words.len().into()),
+                    ("best_guess", (&best.word).into()),
+                    ("best_entropy", best.entropy.into()),
+                ],
+            );
         }
+        Ok(ranked)
+    }
+
+    /// Scores the given guesses against the remaining candidates, keeping their
+    /// order, e.g. to show the numbers behind a ranking computed elsewhere.
+    pub fn score_guesses(&self, guesses: &[String]) -> Vec<ScoredGuess> {
+        self.score_stats(self.guess_stats(guesses, &self.words))
+    }
+
+    // Applies the strategy to the statistics of each guess
+    fn score_stats(&self, stats: Vec<GuessStats>) -> Vec<ScoredGuess> {
+        stats
+            .into_iter()
+            .map(|stats| ScoredGuess {
+                score: self.strategy.score(&stats),
+                word: stats.word,
+                entropy: stats.entropy,
+                normalized_frequency: stats.normalized_frequency,
+                largest_bucket: stats.largest_bucket,
+                expected_size: stats.expected_size,
+                is_candidate: stats.is_candidate,
+            })
+            .collect()
+    }
 
-        // Add new yellow (wrong placed) constraints
-        for &constraint in wrong_placed_chars {
-            if !self.accumulated_wrong_placed_chars.contains(&constraint) {
-                self.accumulated_wrong_placed_chars.push(constraint);
+    /// Computes the statistics of each guess against the given candidates, which the
+    /// strategies use to score the guesses.
+    pub fn guess_stats(&self, guesses: &[String], candidates: &[String]) -> Vec<GuessStats> {
+        let answer_ids = self.answer_ids(candidates);
+        self.compute_guess_stats(guesses, candidates, answer_ids.as_deref())
+    }
+
+    fn compute_guess_stats(
+        &self,
+        guesses: &[String],
+        candidates: &[String],
+        answer_ids: Option<&[usize]>,
+    ) -> Vec<GuessStats> {
+        let candidate_set: HashSet<&str> = candidates.iter().map(|w| w.as_str()).collect();
+        let total = candidates.len();
+
+        // Calculate the pattern distribution of every guess in parallel, along with its frequency
+        let mut stats = parallel_map(guesses, self.threads, |guess| {
+            let pattern_counts = self.pattern_histogram(guess, candidates, answer_ids);
+            // Only words that can still be the answer get credit for their frequency
+            let is_candidate = candidate_set.contains(guess.as_str());
+            let frequency = if is_candidate {
+                self.word_frequencies.get(guess).copied().unwrap_or(0.0)
+            } else {
+                0.0
+            };
+            let sum_squares: usize = pattern_counts.iter().map(|&count| count * count).sum();
+
+            GuessStats {
+                word: guess.clone(),
+                entropy: entropy_of(&pattern_counts, total),
+                frequency,
+                normalized_entropy: 0.0, // Will be normalized later
+                normalized_frequency: 0.0,
+                largest_bucket: pattern_counts.iter().copied().max().unwrap_or(0),
+                expected_size: if total > 0 { sum_squares as f64 / total as f64 } else { 0.0 },
+                is_candidate,
+                probability: if is_candidate { 1.0 / total as f64 } else { 0.0 },
+                candidate_count: total,
             }
+        });
+
+        // Normalize values across the guesses
+        let (min_entropy, max_entropy) = min_max(stats.iter().map(|s| s.entropy));
+        let (min_frequency, max_frequency) = min_max(stats.iter().map(|s| s.frequency));
+        for s in &mut stats {
+            s.normalized_entropy = self.normalization.apply(s.entropy, min_entropy, max_entropy);
+            s.normalized_frequency = self.normalization.apply(s.frequency, min_frequency, max_frequency);
+        }
+        stats
+    }
+
+    // Moves the best `top_k` guesses to the front, sorted by the expected number of
+    // candidates left after two guesses (lower is better). The rest keeps its order.
+    fn rerank_two_step(
+        &self,
+        mut ranked: Vec<ScoredGuess>,
+        top_k: usize,
+        candidates: &[String],
+        answer_ids: Option<&[usize]>,
+    ) -> Vec<ScoredGuess> {
+        let rest = ranked.split_off(top_k.min(ranked.len()));
+        let top_words: Vec<String> = ranked.iter().map(|guess| guess.word.clone()).collect();
+        let expected = parallel_map(&top_words, self.threads, |guess| {
+            self.expected_remaining_after_two(guess, &top_words, candidates, answer_ids)
+        });
+
+        // Stable sort, so ties keep the single-step order
+        let mut top: Vec<(ScoredGuess, f64)> = ranked.into_iter().zip(expected).collect();
+        top.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        top.into_iter().map(|(guess, _)| guess).chain(rest).collect()
+    }
+
+    /// Expected number of candidates left after playing `guess` and then, for each
+    /// possible feedback, the follow-up that leaves the fewest candidates on average.
+    /// A solved game counts as zero candidates left. The follow-ups considered are
+    /// the `follow_ups` guesses plus the candidates left after the first feedback.
+    pub fn expected_remaining_after_two(
+        &self,
+        guess: &str,
+        follow_ups: &[String],
+        candidates: &[String],
+        answer_ids: Option<&[usize]>,
+    ) -> f64 {
+        // Split the candidates by the feedback they would give to the first guess
+        let mut buckets: HashMap<PatternCode, Vec<usize>> = HashMap::new();
+        for (i, code) in self.feedback_codes(guess, candidates, answer_ids).into_iter().enumerate() {
+            buckets.entry(code).or_default().push(i);
         }
 
-        // For red/black (absent) constraints, need to check they don't conflict with yellow constraints
-        for &(c, pos) in absent_chars {
-            // Only add if this character isn't already known to be in the word
-            if !self.accumulated_wrong_placed_chars.iter().any(|(ch, _)| *ch == c) &&
-               !self.accumulated_present_chars.iter().any(|(ch, _)| *ch == c) {
-                self.accumulated_absent_chars.push((c, pos));
+        let mut total_remaining = 0.0;
+        for bucket in buckets.values() {
+            // The first guess was the answer, or a single candidate is left to guess
+            if bucket.len() == 1 {
+                continue;
             }
+
+            let bucket_words: Vec<String> = bucket.iter().map(|&i| candidates[i].clone()).collect();
+            let bucket_ids: Option<Vec<usize>> = answer_ids.map(|ids| bucket.iter().map(|&i| ids[i]).collect());
+
+            let best = follow_ups
+                .iter()
+                .chain(bucket_words.iter())
+                .map(|follow_up| {
+                    let counts = self.pattern_histogram(follow_up, &bucket_words, bucket_ids.as_deref());
+                    let sum_squares: usize = counts.iter().map(|&count| count * count).sum();
+                    // Guessing the answer itself leaves nothing to find
+                    let solved = usize::from(bucket_words.contains(follow_up));
+                    (sum_squares - solved) as f64
+                })
+                .fold(f64::INFINITY, f64::min);
+
+            // Each answer in the bucket leaves best / |bucket| candidates on average
+            total_remaining += best;
         }
+
+        total_remaining / candidates.len() as f64
     }
 
-    // New method that filters based on all accumulated constraints
-    fn filter_words_with_all_constraints(&self, words: &[String]) -> Vec<String> {
-        words.iter().filter(|word| {
-            // Check all accumulated absent characters
-            for &(c, _) in &self.accumulated_absent_chars {
-                if word.contains(c) {
-                    return false;
-                }
+    /// Adds a (word, color state) pair to the history, unless already there,
+    /// and narrows the candidates down, without ranking the guesses.
+    /// The pair is rejected, leaving the history untouched, if it is malformed or
+    /// if no candidate could have produced it. Rows undone so far can no longer be redone.
+    pub fn record_feedback(&mut self, word: &str, color_state: &Pattern) -> Result<(), SolverError> {
+        let word = self.validate_row(word, color_state)?;
+        if self.previous_words.iter().any(|(w, c)| *w == word && c == color_state) {
+            return Ok(());
+        }
+
+        let mut history = self.previous_words.clone();
+        history.push((word.clone(), color_state.clone()));
+        self.set_history(history)?;
+        self.undone_words.clear();
+        self.log_history_change("feedback", &word, color_state);
+        Ok(())
+    }
+
+    // Logs a change to the history with the number of candidates left
+    fn log_history_change(&self, event: &str, word: &str, color_state: &Pattern) {
+        logging::log(
+            LogLevel::Debug,
+            event,
+            &[
+                ("guess", word.into()),
+                ("pattern", (&color_state.to_string()).into()),
+                ("rows", self.previous_words.len().into()),
+                ("candidates", self.words.len().into()),
+            ],
+        );
+    }
+
+    /// The guesses played so far with their feedback, in order.
+    pub fn history(&self) -> &[(String, Pattern)] {
+        &self.previous_words
+    }
+
+    /// Replaces the whole history and recomputes the candidates from it.
+    /// The history is left untouched if some row is malformed or if the rows
+    /// contradict each other, in which case the error tells where the conflict is.
+    pub fn set_history(&mut self, history: Vec<(String, Pattern)>) -> Result<(), SolverError> {
+        let history = history
+            .into_iter()
+            .map(|(word, pattern)| Ok((self.validate_row(&word, &pattern)?, pattern)))
+            .collect::<Result<Vec<_>, SolverError>>()?;
+
+        let previous = std::mem::replace(&mut self.previous_words, history);
+        self.recompute();
+
+        if self.words.is_empty() && !self.answers.is_empty() {
+            let conflict = self.find_conflict(&self.previous_words).map(Box::new);
+            // Report the latest row involved, the history cannot be empty here
+            let row = conflict
+                .as_ref()
+                .and_then(|conflict| conflict.rows.last().copied())
+                .unwrap_or(self.previous_words.len() - 1);
+            let (guess, pattern) = self.previous_words[row].clone();
+            self.previous_words = previous;
+            self.recompute();
+            logging::log(
+                LogLevel::Warn,
+                "inconsistent_feedback",
+                &[("guess", (&guess).into()), ("pattern", (&pattern.to_string()).into())],
+            );
+            return Err(SolverError::InconsistentFeedback { guess, pattern, conflict });
+        }
+        Ok(())
+    }
+
+    /// Replaces the row at `index` of the history, e.g. to fix a typo, and
+    /// recomputes the candidates. The history is left untouched on error.
+    pub fn replace_row(&mut self, index: usize, word: &str, color_state: &Pattern) -> Result<(), SolverError> {
+        if index >= self.previous_words.len() {
+            return Err(SolverError::NoSuchRow { index, rows: self.previous_words.len() });
+        }
+        let word = self.validate_row(word, color_state)?;
+        let mut history = self.previous_words.clone();
+        history[index] = (word.clone(), color_state.clone());
+        self.set_history(history)?;
+        self.log_history_change("replace_row", &word, color_state);
+        Ok(())
+    }
+
+    /// Removes the last row of the history, returning it. It can be restored with `redo`.
+    pub fn undo(&mut self) -> Option<(String, Pattern)> {
+        let row = self.previous_words.pop()?;
+        self.undone_words.push(row.clone());
+        self.recompute();
+        self.log_history_change("undo", &row.0, &row.1);
+        Some(row)
+    }
+
+    /// Restores the last row removed by `undo`, returning it, or `Ok(None)` if
+    /// there is nothing to redo. The row is kept for later if the history
+    /// changed meanwhile and it no longer fits.
+    pub fn redo(&mut self) -> Result<Option<(String, Pattern)>, SolverError> {
+        let Some(row) = self.undone_words.pop() else {
+            return Ok(None);
+        };
+        let mut history = self.previous_words.clone();
+        history.push(row.clone());
+        if let Err(err) = self.set_history(history) {
+            self.undone_words.push(row);
+            return Err(err);
+        }
+        self.log_history_change("redo", &row.0, &row.1);
+        Ok(Some(row))
+    }
+
+    /// Returns true if there is a row that `redo` can restore.
+    pub fn can_redo(&self) -> bool {
+        !self.undone_words.is_empty()
+    }
+
+    /// Checks whether some answer is consistent with every row of `history` and,
+    /// if none is, finds the rows and tiles that contradict each other and a
+    /// single tile flip that would fix them.
+    pub fn find_conflict(&self, history: &[(String, Pattern)]) -> Option<Conflict> {
+        find_conflict(history, &self.answers)
+    }
+
+    // Checks that a row of the history is well formed, returning its word as stored
+    fn validate_row(&self, word: &str, color_state: &Pattern) -> Result<String, SolverError> {
+        let word = self.validate_guess(word)?;
+        if color_state.len() != self.word_length {
+            return Err(SolverError::InvalidLength {
+                input: color_state.to_string(),
+                expected: self.word_length,
+                found: color_state.len(),
+            });
+        }
+        Ok(word)
+    }
+
+    /// Checks that `word` could be a guess and returns it as the word lists
+    /// spell it: lowercased and with its accents folded (see
+    /// `Language::normalize_word`). It must only contain letters of the
+    /// language and have the length of the words being played.
+    pub fn validate_guess(&self, word: &str) -> Result<String, SolverError> {
+        let normalized = self.language.normalize_word(word).map_err(|_| {
+            let character = word.trim().chars().find(|&c| self.language.normalize_char(c).is_err()).unwrap_or_default();
+            SolverError::InvalidCharacter { word: word.to_string(), character }
+        })?;
+        let length = normalized.chars().count();
+        if length != self.word_length {
+            return Err(SolverError::InvalidLength {
+                input: word.to_string(),
+                expected: self.word_length,
+                found: length,
+            });
+        }
+        Ok(normalized)
+    }
+
+    // Recomputes the accumulated constraints and the candidates from the history.
+    // A guessed word is filtered out by its own row, unless it was the answer.
+    fn recompute(&mut self) {
+        self.constraints = Constraints::new(self.word_length);
+        for (word, pattern) in &self.previous_words {
+            self.constraints.add_feedback(word, pattern);
+        }
+        self.words = self.filter_candidates(&self.answers);
+    }
+
+    /// Checks a guess against the hard-mode rules given the hints revealed so far,
+    /// returning a description of the broken rule if the guess is not allowed.
+    pub fn hard_mode_violation(&self, guess: &str) -> Option<String> {
+        self.constraints.hard_mode_violation(guess)
+    }
+
+    /// Returns the words in the list that are still consistent with the history.
+    pub fn remaining_candidates(&self) -> Vec<String> {
+        self.words.clone()
+    }
+
+    /// Number of words still consistent with the history.
+    pub fn candidate_count(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Remaining candidates with the probability of each one being the answer,
+    /// proportional to its frequency. A candidate without a frequency weighs as
+    /// much as the rarest one with a frequency, so no candidate gets a zero
+    /// chance. Without frequencies every candidate is equally likely.
+    pub fn candidate_probabilities(&self) -> Vec<(String, f64)> {
+        // Candidates without a frequency count as the rarest one with a frequency,
+        // they can still be the answer
+        let rarest = self
+            .words
+            .iter()
+            .filter_map(|word| self.word_frequencies.get(word).copied())
+            .filter(|&frequency| frequency > 0.0)
+            .fold(f64::INFINITY, f64::min);
+        let weight = |word: &String| match self.word_frequencies.get(word).copied() {
+            Some(frequency) if frequency > 0.0 => frequency,
+            _ if rarest.is_finite() => rarest,
+            _ => 1.0,
+        };
+        let total: f64 = self.words.iter().map(weight).sum();
+        self.words.iter().map(|word| (word.clone(), weight(word) / total)).collect()
+    }
+
+    // Filters the words using the strategy selected by filter_mode
+    fn filter_candidates(&self, words: &[String]) -> Vec<String> {
+        match self.filter_mode {
+            FilterMode::Constraints => self.filter_words_with_all_constraints(words),
+            FilterMode::PatternConsistency => self.filter_words_with_patterns(words),
+        }
+    }
+
+    /// Filters based on all accumulated letter constraints.
+    pub fn filter_words_with_all_constraints(&self, words: &[String]) -> Vec<String> {
+        words
+            .iter()
+            .filter(|word| self.constraints.matches(word))
+            .cloned()
+            .collect()
+    }
+
+    /// Keeps the words `w` such that `get_feedback_pattern(guess, w)` equals the observed
+    /// pattern for every `(guess, pattern)` in `previous_words`.
+    pub fn filter_words_with_patterns(&self, words: &[String]) -> Vec<String> {
+        let answer_ids = self.answer_ids(words);
+        let mut keep = vec![true; words.len()];
+        for (guess, pattern) in &self.previous_words {
+            if pattern.len() != self.word_length {
+                return Vec::new();
             }
-            
-            // Check all accumulated correctly placed characters
-            for &(c, pos) in &self.accumulated_present_chars {
-                if word.chars().nth(pos).unwrap_or(' ') != c {
-                    return false;
-                }
+            let codes = self.feedback_codes(guess, words, answer_ids.as_deref());
+            for (kept, code) in keep.iter_mut().zip(codes) {
+                *kept &= code == pattern.code();
             }
-            
-            // Check all accumulated wrongly placed characters
-            for &(c, pos) in &self.accumulated_wrong_placed_chars {
-                if word.chars().nth(pos).unwrap_or(' ') == c || !word.contains(c) {
-                    return false;
-                }
+        }
+        words.iter().zip(keep).filter(|(_, kept)| *kept).map(|(word, _)| word.clone()).collect()
+    }
+
+    /// Records a guess and its feedback, then narrows the candidate answers down.
+    pub fn add_used_word(&mut self, word: &str, color_state: &Pattern) -> Result<(), SolverError> {
+        self.record_feedback(word, color_state)
+    }
+}
+
+// Entropy of a distribution given as counts: H = - Σ p(pattern) log₂(p(pattern))
+fn entropy_of(counts: &[usize], total: usize) -> f64 {
+    let total = total as f64;
+    let mut entropy = 0.0;
+    for &count in counts.iter().filter(|&&count| count > 0) {
+        let p = count as f64 / total;
+        entropy -= p * p.log2();
+    }
+    entropy
+}
+
+// Lowest and highest of some values, (0, 0) if there are none
+fn min_max(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    values.fold(None, |acc: Option<(f64, f64)>, v| match acc {
+        Some((min, max)) => Some((min.min(v), max.max(v))),
+        None => Some((v, v)),
+    })
+    .unwrap_or((0.0, 0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feedback::feedback_pattern;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    // Guesses with repeated letters, the cases the constraints get wrong most easily
+    const REPEATED_LETTER_GUESSES: [&str; 5] = ["sassi", "lolla", "palla", "sasso", "seria"];
+
+    #[test]
+    fn both_filters_keep_the_same_candidates() {
+        let lists = WordLists::embedded();
+        for answer in lists.answers.iter().step_by(20) {
+            let mut solver = Solver::with_word_lists(lists.clone());
+            for guess in REPEATED_LETTER_GUESSES {
+                solver.record_feedback(guess, &feedback_pattern(guess, answer)).unwrap();
+                assert_eq!(
+                    solver.filter_words_with_all_constraints(&lists.answers),
+                    solver.filter_words_with_patterns(&lists.answers),
+                    "filters disagree for '{}' after {:?}",
+                    answer,
+                    solver.history()
+                );
             }
-            true
-        }).cloned().collect()
+        }
     }
 
-    pub fn add_used_word(&mut self, word: &str, color_state: &str) {
-        self.previous_words.push((word.to_string(), color_state.to_string()));
-        
-        // Update constraints based on this word's feedback
-        let mut absent_chars: Vec<(char, usize)> = Vec::new();
-        let mut present_chars: Vec<(char, usize)> = Vec::new();
-        let mut wrong_placed_chars: Vec<(char, usize)> = Vec::new();
-
-        for (i, c) in word.chars().enumerate() {
-            let state = color_state.chars().nth(i).unwrap();
-            if state == 'R' {
-                absent_chars.push((c, i));
-            } else if state == 'G' {
-                present_chars.push((c, i));
-            } else if state == 'Y' {
-                wrong_placed_chars.push((c, i));
+    #[test]
+    fn pattern_table_filters_like_feedback_codes() {
+        let lists = WordLists::embedded();
+        let guesses: Vec<String> = REPEATED_LETTER_GUESSES.iter().map(|guess| guess.to_string()).collect();
+        let table = Arc::new(PatternTable::build(&guesses, &lists.answers));
+        for answer in lists.answers.iter().step_by(50) {
+            let mut plain = Solver::with_word_lists(lists.clone());
+            let mut tabled = Solver::with_word_lists(lists.clone());
+            tabled.set_pattern_table(Arc::clone(&table));
+            for guess in REPEATED_LETTER_GUESSES {
+                let pattern = feedback_pattern(guess, answer);
+                plain.record_feedback(guess, &pattern).unwrap();
+                tabled.record_feedback(guess, &pattern).unwrap();
+                assert_eq!(plain.filter_words_with_patterns(&lists.answers), tabled.filter_words_with_patterns(&lists.answers));
             }
         }
+    }
 
-        self.update_accumulated_constraints(&absent_chars, &present_chars, &wrong_placed_chars);
-        
-        // Remove the word itself from candidates
-        self.words.retain(|w| w != word);
-        
-        // Apply all constraints to further filter the word list
-        let filtered_words = self.filter_words_with_all_constraints(&self.words);
-        self.words = filtered_words;
+    #[test]
+    fn answer_survives_random_feedback() {
+        let lists = WordLists::embedded();
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..50 {
+            let answer = lists.answers.choose(&mut rng).unwrap();
+            let mut solver = Solver::with_word_lists(lists.clone());
+            for _ in 0..4 {
+                let guess = lists.guesses.choose(&mut rng).unwrap();
+                solver.record_feedback(guess, &feedback_pattern(guess, answer)).unwrap();
+                assert!(
+                    solver.filter_words_with_all_constraints(&lists.answers).contains(answer),
+                    "'{}' filtered out by constraints after {:?}",
+                    answer,
+                    solver.history()
+                );
+                assert!(
+                    solver.filter_words_with_patterns(&lists.answers).contains(answer),
+                    "'{}' filtered out by patterns after {:?}",
+                    answer,
+                    solver.history()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn guesses_are_lowercased_and_folded_before_recording() {
+        let lists = WordLists::embedded();
+        let mut lowercase = Solver::with_word_lists(lists.clone());
+        lowercase.record_feedback("seria", &"RRRRR".parse().unwrap()).unwrap();
+
+        let mut uppercase = Solver::with_word_lists(lists.clone());
+        uppercase.record_feedback("SERIA", &"RRRRR".parse().unwrap()).unwrap();
+        assert_eq!(uppercase.history(), lowercase.history());
+        assert_eq!(uppercase.remaining_candidates(), lowercase.remaining_candidates());
+
+        let mut uppercase = Solver::with_word_lists(lists.clone());
+        assert!(uppercase.record_feedback("SERIA", &"RRGRY".parse().unwrap()).is_ok());
+
+        let mut accented = Solver::with_word_lists(lists);
+        accented.record_feedback("sería", &"RRGRY".parse().unwrap()).unwrap();
+        assert_eq!(accented.history()[0].0, "seria");
+    }
+
+    #[test]
+    fn guesses_outside_the_alphabet_are_rejected() {
+        let mut solver = Solver::new();
+        match solver.record_feedback("ser1a", &"RRRRR".parse().unwrap()) {
+            Err(SolverError::InvalidCharacter { character, .. }) => assert_eq!(character, '1'),
+            other => panic!("expected InvalidCharacter, got {:?}", other),
+        }
+        assert!(solver.history().is_empty());
+    }
+
+    #[test]
+    fn every_candidate_has_a_chance() {
+        let solver = Solver::new();
+        let probabilities = solver.candidate_probabilities();
+        assert!(probabilities.iter().all(|(_, probability)| *probability > 0.0));
+        let total: f64 = probabilities.iter().map(|(_, probability)| probability).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+
+        let english = Solver::with_word_lists(crate::language::ENGLISH.word_lists());
+        let probabilities = english.candidate_probabilities();
+        assert!(probabilities.windows(2).all(|pair| pair[0].1 == pair[1].1));
+    }
+
+    // A solver with the feedback of `guesses` against `answer`
+    fn solver_after(answer: &str, guesses: &[&str]) -> Solver {
+        let mut solver = Solver::new();
+        for guess in guesses {
+            solver.record_feedback(guess, &feedback_pattern(guess, answer)).unwrap();
+        }
+        solver
+    }
+
+    #[test]
+    fn undo_then_redo_restores_the_candidates() {
+        let mut solver = solver_after("carta", &["seria", "palla"]);
+        let history = solver.history().to_vec();
+        let candidates = solver.remaining_candidates();
+
+        assert_eq!(solver.undo(), Some(history[1].clone()));
+        assert_eq!(solver.remaining_candidates(), solver_after("carta", &["seria"]).remaining_candidates());
+        assert_eq!(solver.redo().unwrap(), Some(history[1].clone()));
+        assert_eq!(solver.history(), history);
+        assert_eq!(solver.remaining_candidates(), candidates);
+        assert!(solver.redo().unwrap().is_none());
+    }
+
+    #[test]
+    fn redo_keeps_a_row_that_no_longer_fits() {
+        let mut solver = solver_after("carta", &["seria", "palla"]);
+        solver.undo();
+        // Make the first row point at an answer the undone row rules out
+        let other = solver
+            .answers
+            .iter()
+            .find(|word| feedback_pattern("palla", word) != feedback_pattern("palla", "carta"))
+            .unwrap()
+            .clone();
+        solver.replace_row(0, &other, &"GGGGG".parse().unwrap()).unwrap();
+
+        assert!(matches!(solver.redo(), Err(SolverError::InconsistentFeedback { .. })));
+        assert!(solver.can_redo());
+        assert_eq!(solver.history().len(), 1);
+        assert_eq!(solver.remaining_candidates(), [other]);
+    }
+
+    #[test]
+    fn failed_replace_row_leaves_the_history_untouched() {
+        let mut solver = solver_after("carta", &["seria", "palla"]);
+        let history = solver.history().to_vec();
+        let candidates = solver.remaining_candidates();
+
+        // Winning with "palla" contradicts the second row, which did not
+        assert!(matches!(
+            solver.replace_row(0, "palla", &"GGGGG".parse().unwrap()),
+            Err(SolverError::InconsistentFeedback { .. })
+        ));
+        assert!(matches!(
+            solver.replace_row(2, "seria", &"GGGGG".parse().unwrap()),
+            Err(SolverError::NoSuchRow { index: 2, rows: 2 })
+        ));
+        assert_eq!(solver.history(), history);
+        assert_eq!(solver.remaining_candidates(), candidates);
+    }
+
+    #[test]
+    fn closest_guesses_rank_by_distance_then_candidates_then_frequency() {
+        let words = |list: &[&str]| list.iter().map(|word| word.to_string()).collect::<Vec<_>>();
+        let lists = WordLists {
+            answers: words(&["carta", "marta", "porta"]),
+            guesses: words(&["carta", "marta", "parta", "darta", "farta", "porta", "corta", "zzzzz"]),
+            frequencies: [("carta", 1.0), ("marta", 5.0), ("parta", 100.0)]
+                .into_iter()
+                .map(|(word, frequency)| (word.to_string(), frequency))
+                .collect(),
+        };
+        let solver = Solver::with_word_lists(lists);
 
-        log_to_file(&format!("Word '{}' used. Remaining words: {}", 
-                           word, self.words.len()));
+        assert_eq!(solver.closest_guesses("xarta", 1, 10), ["marta", "carta", "parta", "darta", "farta"]);
+        assert_eq!(
+            solver.closest_guesses("xarta", 2, 10),
+            ["marta", "carta", "parta", "darta", "farta", "porta", "corta"]
+        );
+        assert_eq!(solver.closest_guesses("xarta", 2, 2), ["marta", "carta"]);
+        assert!(solver.closest_guesses("xarta", 0, 10).is_empty());
     }
 }
@@ -0,0 +1,190 @@
+use std::sync::Arc;
+
+/// What is known about a guess when scored against the remaining candidates.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GuessStats {
+    pub word: String,
+    /// Expected information gain in bits.
+    pub entropy: f64,
+    /// Word frequency, zero for words that cannot be the answer.
+    pub frequency: f64,
+    /// Entropy normalized across the ranked guesses, see `Normalization`.
+    pub normalized_entropy: f64,
+    /// Frequency normalized across the ranked guesses, see `Normalization`.
+    pub normalized_frequency: f64,
+    /// Size of the largest group of candidates giving the same feedback.
+    pub largest_bucket: usize,
+    /// Expected number of candidates left after the feedback.
+    pub expected_size: f64,
+    /// Whether the guess can still be the answer.
+    pub is_candidate: bool,
+    /// Probability that the guess is the answer, assuming every candidate is equally likely.
+    pub probability: f64,
+    /// Number of candidates left before the guess.
+    pub candidate_count: usize,
+}
+
+/// How entropy and frequency are scaled before being combined.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Normalization {
+    /// Divide by the highest value, so the best guess gets 1.
+    Max,
+    /// Map the lowest value to 0 and the highest to 1.
+    MinMax,
+    /// Use the raw values.
+    None,
+}
+
+impl Normalization {
+    pub const ALL: [Normalization; 3] = [Normalization::Max, Normalization::MinMax, Normalization::None];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Normalization::Max => "max",
+            Normalization::MinMax => "min-max",
+            Normalization::None => "none",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|n| n.name() == name)
+    }
+
+    /// Normalizes `value`, given the lowest and highest values among the guesses.
+    pub fn apply(&self, value: f64, min: f64, max: f64) -> f64 {
+        match self {
+            Normalization::Max => if max > 0.0 { value / max } else { 0.0 },
+            Normalization::MinMax => if max > min { (value - min) / (max - min) } else { 0.0 },
+            Normalization::None => value,
+        }
+    }
+}
+
+/// Scores the guesses ranked by the `Solver`.
+pub trait Strategy: Send + Sync {
+    /// Name used to select the strategy, e.g. from the command line.
+    fn name(&self) -> &'static str;
+
+    /// Scores a guess, higher is better.
+    fn score(&self, stats: &GuessStats) -> f64;
+}
+
+/// Pure expected information gain.
+pub struct EntropyStrategy;
+
+impl Strategy for EntropyStrategy {
+    fn name(&self) -> &'static str {
+        "entropy"
+    }
+
+    fn score(&self, stats: &GuessStats) -> f64 {
+        stats.entropy
+    }
+}
+
+/// Weighted sum of the normalized entropy and frequency.
+pub struct BlendStrategy {
+    pub entropy_weight: f64,
+    pub frequency_weight: f64,
+}
+
+impl Default for BlendStrategy {
+    fn default() -> Self {
+        BlendStrategy {
+            entropy_weight: 0.8,   // Prioritize entropy (information gain)
+            frequency_weight: 0.2, // But also consider frequency
+        }
+    }
+}
+
+impl Strategy for BlendStrategy {
+    fn name(&self) -> &'static str {
+        "blend"
+    }
+
+    fn score(&self, stats: &GuessStats) -> f64 {
+        self.entropy_weight * stats.normalized_entropy + self.frequency_weight * stats.normalized_frequency
+    }
+}
+
+/// Minimizes the worst case, i.e. the largest group of candidates left.
+pub struct MinimaxStrategy;
+
+impl Strategy for MinimaxStrategy {
+    fn name(&self) -> &'static str {
+        "minimax"
+    }
+
+    fn score(&self, stats: &GuessStats) -> f64 {
+        -(stats.largest_bucket as f64)
+    }
+}
+
+/// Minimizes the expected number of candidates left.
+pub struct ExpectedSizeStrategy;
+
+impl Strategy for ExpectedSizeStrategy {
+    fn name(&self) -> &'static str {
+        "expected-size"
+    }
+
+    fn score(&self, stats: &GuessStats) -> f64 {
+        -stats.expected_size
+    }
+}
+
+/// Plays the most common word that can still be the answer.
+pub struct FrequencyStrategy;
+
+impl Strategy for FrequencyStrategy {
+    fn name(&self) -> &'static str {
+        "frequency"
+    }
+
+    fn score(&self, stats: &GuessStats) -> f64 {
+        stats.frequency
+    }
+}
+
+/// Minimizes the expected number of guesses: the guess wins right away with
+/// probability p, otherwise it takes one more guess plus the guesses needed to
+/// clear the uncertainty left, estimated as the remaining bits / `bits_per_guess`.
+pub struct ExpectedGuessesStrategy {
+    pub bits_per_guess: f64,
+}
+
+impl Default for ExpectedGuessesStrategy {
+    fn default() -> Self {
+        ExpectedGuessesStrategy { bits_per_guess: 3.0 }
+    }
+}
+
+impl Strategy for ExpectedGuessesStrategy {
+    fn name(&self) -> &'static str {
+        "expected-guesses"
+    }
+
+    fn score(&self, stats: &GuessStats) -> f64 {
+        let uncertainty = (stats.candidate_count.max(1) as f64).log2();
+        let bits_left = (uncertainty - stats.entropy).max(0.0);
+        let guesses_to_finish = 1.0 + bits_left / self.bits_per_guess.max(f64::EPSILON);
+        let expected = stats.probability + (1.0 - stats.probability) * (1.0 + guesses_to_finish);
+        -expected
+    }
+}
+
+/// Names of the strategies accepted by `strategy_by_name`.
+pub const STRATEGY_NAMES: &[&str] = &["entropy", "blend", "minimax", "expected-size", "frequency", "expected-guesses"];
+
+/// Returns the strategy with the given name, with its default settings.
+pub fn strategy_by_name(name: &str) -> Option<Arc<dyn Strategy>> {
+    match name {
+        "entropy" => Some(Arc::new(EntropyStrategy)),
+        "blend" => Some(Arc::new(BlendStrategy::default())),
+        "minimax" => Some(Arc::new(MinimaxStrategy)),
+        "expected-size" => Some(Arc::new(ExpectedSizeStrategy)),
+        "frequency" => Some(Arc::new(FrequencyStrategy)),
+        "expected-guesses" => Some(Arc::new(ExpectedGuessesStrategy::default())),
+        _ => None,
+    }
+}
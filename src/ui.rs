@@ -1,10 +1,11 @@
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table},
+    widgets::{Block, Borders, Cell, Clear, List, ListItem, Paragraph, Row, Table, Wrap},
     Frame,
 };
+use parole_bot::{config::SolverConfig, solver::{GuessPool, Lookahead}};
 use crate::app::{App, CurrentScreen};
 
 pub fn ui(frame: &mut Frame, app: &mut App) {
@@ -17,7 +18,8 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
         .constraints([
             Constraint::Length(3),  // Title
             Constraint::Min(1),     // Main body
-            Constraint::Length(3),  // Footer
+            Constraint::Length(1),  // Status line
+            Constraint::Length(4),  // Footer
         ])
         .split(frame.area());
 
@@ -26,8 +28,13 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
         .borders(Borders::ALL)
         .style(Style::default());
 
+    let title_text = if app.solver.hard_mode {
+        format!("Parole bot - {} (hard mode)", app.game.language.name)
+    } else {
+        format!("Parole bot - {}", app.game.language.name)
+    };
     let title = Paragraph::new(Text::styled(
-        "Parole bot",
+        title_text,
         Style::default().fg(Color::Yellow),
     ))
     .block(title_block)
@@ -41,32 +48,60 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(chunks[1]);
 
-    // Split right side vertically, but only use the top half
-    let right_top_half = Layout::default()
+    // Split right side vertically: top suggestions above, the full table below
+    let right_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(main_chunks[1])[0];  // Only use the top half!
+        .split(main_chunks[1]);
+    let right_top_half = right_chunks[0];
 
     // Left and right blocks
+    // A key that is not a letter of the language is shown for a moment
+    let grid_title = match app.recently_rejected_key() {
+        Some(c) => Line::from(vec![
+            Span::raw("Words Grid "),
+            Span::styled(
+                format!("'{}' is not a letter in {}", c, app.game.language.name),
+                Style::default().fg(Color::LightRed),
+            ),
+        ]),
+        None => Line::from("Words Grid"),
+    };
     let left_block = Block::default()
         .borders(Borders::ALL)
-        .title("Words Grid");
+        .title(grid_title);
 
     frame.render_widget(left_block, main_chunks[0]);
 
     render_grid(&main_chunks, app, frame);
 
     // Right block - only render in the top half
+    let pool_label = match app.solver.guess_pool {
+        GuessPool::Candidates => "candidates only",
+        GuessPool::FullDictionary => "full dictionary",
+    };
+    let lookahead_label = match app.solver.lookahead {
+        Lookahead::Greedy => "greedy",
+        Lookahead::TwoStep { .. } => "two-step",
+    };
     let right_block = Block::default()
         .borders(Borders::ALL)
-        .title("Top 3 Suggested Words");
+        .title(format!("Top 3 Suggested Words ({}, {})", pool_label, lookahead_label));
 
     // Create text for top 3 words
     let words_text = if app.is_solving {
-        vec![Line::from(Span::styled(
+        let mut lines = vec![Line::from(Span::styled(
             "Calculating next words...",
             Style::default().fg(Color::Yellow),
-        ))]
+        ))];
+        // Before the first guess, the usual opener can be played meanwhile
+        if let Some(opener) = app.default_opener().filter(|_| app.solver.history().is_empty()) {
+            lines.push(Line::from(Span::styled(
+                format!("Usual opener: {}", opener),
+                Style::default().fg(Color::Cyan),
+            )));
+        }
+        lines
     } else if app.next_possible_words.is_empty() {
         vec![Line::from(Span::styled(
             "No suggestions available",
@@ -77,11 +112,19 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
             .iter()
             .take(3)
             .enumerate()
-            .map(|(i, word)| {
-                Line::from(Span::styled(
-                    format!("{}. {}", i + 1, word),
-                    Style::default().fg(Color::Green),
-                ))
+            .map(|(i, guess)| {
+                // Words that can still be the answer are marked, the others only gather information
+                if guess.is_candidate {
+                    Line::from(Span::styled(
+                        format!("{}. {} ({:.2} bits, possible answer)", i + 1, guess.word, guess.entropy),
+                        Style::default().fg(Color::Green),
+                    ))
+                } else {
+                    Line::from(Span::styled(
+                        format!("{}. {} ({:.2} bits)", i + 1, guess.word, guess.entropy),
+                        Style::default().fg(Color::Cyan),
+                    ))
+                }
             })
             .collect::<Vec<Line>>()
     };
@@ -92,6 +135,26 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
 
     // Only render in the top half of the right side
     frame.render_widget(right_paragraph, right_top_half);
+    app.top_suggestions_area = right_top_half;
+
+    render_suggestions(right_chunks[1], app, frame);
+
+    if app.current_screen == CurrentScreen::Settings {
+        render_settings(chunks[1], app, frame);
+    }
+    if app.current_screen == CurrentScreen::Candidates {
+        render_candidates(chunks[1], app, frame);
+    }
+
+    // Status line with the last message, if any
+    if let Some(message) = &app.status_message {
+        let status = Paragraph::new(Line::from(Span::styled(
+            message.as_str(),
+            Style::default().fg(Color::LightRed),
+        )))
+        .alignment(Alignment::Center);
+        frame.render_widget(status, chunks[2]);
+    }
 
     // Footer with mode and hints - combined into one centered paragraph
     let footer_content = vec![
@@ -103,6 +166,8 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
             CurrentScreen::EditingTileColor => {
                 Span::styled("Editing tile color Mode", Style::default().fg(Color::Yellow))
             }
+            CurrentScreen::Settings => Span::styled("Settings", Style::default().fg(Color::Yellow)),
+            CurrentScreen::Candidates => Span::styled("Candidates", Style::default().fg(Color::Yellow)),
             CurrentScreen::Exiting => Span::styled("Exiting", Style::default().fg(Color::LightRed)),
         }
         .to_owned(),
@@ -124,7 +189,7 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
         Span::styled(" | ", Style::default().fg(Color::White)),
         match app.current_screen {
             CurrentScreen::Main => Span::styled(
-                "(q) to quit",
+                "(q) to quit | (d) toggle full dictionary | (h) toggle hard mode | (l) toggle lookahead | (o) settings | (u)ndo | (r)edo | (g) change language | (↑/↓/PgUp/PgDn) scroll suggestions | (s) sort suggestions | (Enter/1-9/click) use a suggestion | (c) browse candidates",
                 Style::default().fg(Color::Red),
             ),
             CurrentScreen::EditingTileChar => Span::styled(
@@ -132,7 +197,19 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
                 Style::default().fg(Color::Red),
             ),
             CurrentScreen::EditingTileColor => Span::styled(
-                "(q) to quit | (↑/↓/←/→) change tile | (n/p) change color | (Enter) confirm colors | (Esc) exit mode",
+                "(q) to quit | (↑/↓/←/→) change tile | (n/p) change color | (Enter) confirm colors | (f) use correction | (Esc) exit mode",
+                Style::default().fg(Color::Red),
+            ),
+            CurrentScreen::Settings => Span::styled(
+                "(↑/↓) select setting | (←/→) change value | (w) save to file | (Esc) close",
+                Style::default().fg(Color::Red),
+            ),
+            CurrentScreen::Candidates => Span::styled(
+                if app.candidate_chances_differ() {
+                    "(letters) search | (Backspace) delete | (Tab) sort by chance or word | (←/→ or PgUp/PgDn) change page | (Esc) close"
+                } else {
+                    "(letters) search | (Backspace) delete | (←/→ or PgUp/PgDn) change page | (Esc) close"
+                },
                 Style::default().fg(Color::Red),
             ),
             CurrentScreen::Exiting => Span::styled(
@@ -144,9 +221,10 @@ pub fn ui(frame: &mut Frame, app: &mut App) {
 
     let footer = Paragraph::new(Line::from(footer_content))
         .block(Block::default().borders(Borders::ALL))
+        .wrap(Wrap { trim: true })
         .alignment(Alignment::Center);  // Center the footer text
 
-    frame.render_widget(footer, chunks[2]);
+    frame.render_widget(footer, chunks[3]);
 }
 
 pub fn render_grid(main_chunks: &[Rect], app: &mut App, frame: &mut Frame) {
@@ -168,24 +246,38 @@ pub fn render_grid(main_chunks: &[Rect], app: &mut App, frame: &mut Frame) {
         ])
         .split(inner_area)[1];  // Get the middle section
 
+    // Rows shrink to fit when there are many attempts
+    let row_height = (inner_area.height / app.tiles_grid.tiles.len().max(1) as u16).clamp(1, 4);
+
+    let rejected = app.recently_rejected_key().is_some();
+
     // Create rows as before
     let rows = app.tiles_grid.tiles.iter().enumerate().map(|(row_idx, row)| {
         let cells = row.iter().enumerate().map(|(col_idx, tile)| {
             let is_selected = app.selected_tile == (row_idx, col_idx);
             let mut style = Style::default().fg(tile.color.to_color());
             if is_selected {
-                style = style.bg(Color::White);
+                // The selected tile flashes red when a key is rejected
+                let background = if rejected { Color::LightRed } else { Color::White };
+                style = style.bg(background);
             }
-            let cell_content = format!("{:^5}", tile.character.to_string());
+            // Tiles contradicting each other are bracketed and underlined
+            let cell_content = if app.conflict_tiles.contains(&(row_idx, col_idx)) {
+                style = style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+                format!("{:^5}", format!("[{}]", tile.character))
+            } else {
+                format!("{:^5}", tile.character.to_string())
+            };
             Cell::from(Span::styled(cell_content, style))
         })
         .collect::<Vec<_>>();
 
-        Row::new(cells).height(4)
+        Row::new(cells).height(row_height)
     }).collect::<Vec<_>>();
 
     // Make columns fill the available space evenly
-    let widths = vec![Constraint::Percentage(20); app.tiles_grid.tiles[0].len()];
+    let columns = app.word_length() as u32;
+    let widths = vec![Constraint::Ratio(1, columns); columns as usize];
 
     let table = Table::new(rows, widths)
         .block(Block::default())
@@ -195,3 +287,213 @@ pub fn render_grid(main_chunks: &[Rect], app: &mut App, frame: &mut Frame) {
     frame.render_widget(table, inner_area);
 }
 
+// Table of every ranked suggestion with the numbers behind its rank, scrolled
+// with the selection of `list_state`
+pub fn render_suggestions(area: Rect, app: &mut App, frame: &mut Frame) {
+    let block = Block::default().borders(Borders::ALL).title(format!(
+        "Suggestions ({}, sorted by {})",
+        app.next_possible_words.len(),
+        app.suggestion_sort.name()
+    ));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
+        .split(inner);
+
+    let width = app.word_length().max(4);
+    let header = format!(
+        "{:>4}  {:<width$}  {:>7}  {:>5}  {:>6}  {:>5}  {:>6}  answer",
+        "rank", "word", "entropy", "freq", "score", "worst", "exp.",
+        width = width
+    );
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(header, Style::default().add_modifier(Modifier::BOLD)))),
+        chunks[0],
+    );
+
+    // The previous suggestions no longer apply while the next ones are calculated
+    if app.is_solving {
+        app.suggestions_area = Rect::default();
+        frame.render_widget(
+            Paragraph::new(Span::styled("Calculating next words...", Style::default().fg(Color::Yellow))),
+            chunks[1],
+        );
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .sorted_suggestions()
+        .into_iter()
+        .map(|(rank, guess)| {
+            let is_candidate = guess.is_candidate;
+            let color = if is_candidate { Color::Green } else { Color::Cyan };
+            ListItem::new(Line::from(Span::styled(
+                format!(
+                    "{:>4}  {:<width$}  {:>7.3}  {:>5.2}  {:>6.3}  {:>5}  {:>6.1}  {}",
+                    rank + 1,
+                    guess.word,
+                    guess.entropy,
+                    guess.normalized_frequency,
+                    guess.score,
+                    guess.largest_bucket,
+                    guess.expected_size,
+                    if is_candidate { "yes" } else { "" },
+                    width = width
+                ),
+                Style::default().fg(color),
+            )))
+        })
+        .collect();
+
+    let list = List::new(items).highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    frame.render_stateful_widget(list, chunks[1], &mut app.list_state);
+    app.suggestions_area = chunks[1];
+}
+
+pub fn render_candidates(area: Rect, app: &mut App, frame: &mut Frame) {
+    // Popup over most of the main body
+    let popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(10),
+            Constraint::Percentage(80),
+            Constraint::Percentage(10),
+        ])
+        .split(area)[1];
+    let popup = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(20),
+            Constraint::Percentage(60),
+            Constraint::Percentage(20),
+        ])
+        .split(popup)[1];
+
+    let candidates = app.browsed_candidates();
+    let block = Block::default().borders(Borders::ALL).title(format!(
+        "Remaining candidates: {} ({} matching, sorted by {})",
+        app.solver.candidate_count(),
+        candidates.len(),
+        app.shown_candidate_sort().name()
+    ));
+    let inner = block.inner(popup);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(block, popup);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),  // Search
+            Constraint::Min(1),     // Candidates
+            Constraint::Length(1),  // Page
+        ])
+        .split(inner);
+
+    frame.render_widget(
+        Paragraph::new(vec![
+            Line::from(vec![
+                Span::raw("Search: "),
+                Span::styled(format!("{}_", app.candidate_search), Style::default().fg(Color::Yellow)),
+            ]),
+            Line::from(Span::styled(
+                format!("{:>5}  {:<12} {:>9} {:>8}", "#", "word", "frequency", "chance"),
+                Style::default().add_modifier(Modifier::BOLD),
+            )),
+        ]),
+        chunks[0],
+    );
+
+    // The page size follows the height of the popup
+    app.candidate_page_size = (chunks[1].height as usize).max(1);
+    let pages = candidates.len().div_ceil(app.candidate_page_size).max(1);
+    app.candidate_page = app.candidate_page.min(pages - 1);
+
+    let start = app.candidate_page * app.candidate_page_size;
+    let lines: Vec<Line> = candidates
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(app.candidate_page_size)
+        .map(|(i, (word, probability))| {
+            let frequency = app.solver.word_frequencies.get(word).copied().unwrap_or(0.0);
+            Line::from(Span::styled(
+                format!("{:>5}. {:<12} {:>9.2} {:>8}", i + 1, word, frequency, format_chance(*probability)),
+                Style::default().fg(Color::Green),
+            ))
+        })
+        .collect();
+    if lines.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Span::styled("No candidates match", Style::default().fg(Color::DarkGray))),
+            chunks[1],
+        );
+    } else {
+        frame.render_widget(Paragraph::new(lines), chunks[1]);
+    }
+
+    frame.render_widget(
+        Paragraph::new(Span::styled(
+            format!("Page {}/{}", app.candidate_page + 1, pages),
+            Style::default().fg(Color::DarkGray),
+        ))
+        .alignment(Alignment::Right),
+        chunks[2],
+    );
+}
+
+pub fn render_settings(area: Rect, app: &App, frame: &mut Frame) {
+    // Centered popup over the main body
+    let popup = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(SolverConfig::KEYS.len() as u16 + 4),
+            Constraint::Min(0),
+        ])
+        .split(area)[1];
+    let popup = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(25),
+            Constraint::Percentage(50),
+            Constraint::Percentage(25),
+        ])
+        .split(popup)[1];
+
+    let mut lines: Vec<Line> = SolverConfig::KEYS
+        .iter()
+        .enumerate()
+        .map(|(i, key)| {
+            let style = if i == app.selected_setting {
+                Style::default().fg(Color::Black).bg(Color::White)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            Line::from(Span::styled(format!("{:<18} < {} >", key, app.config.get(key)), style))
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Changes apply to the next calculation",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let settings = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title("Settings"));
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(settings, popup);
+}
+
+// Chance of a candidate as a percentage, live candidates never show as 0%
+fn format_chance(probability: f64) -> String {
+    let percentage = probability * 100.0;
+    if percentage > 0.0 && percentage < 0.01 {
+        "<0.01%".to_string()
+    } else {
+        format!("{:.2}%", percentage)
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::error::SolverError;
+use crate::language::{Language, ITALIAN};
+use crate::loader::{load_frequencies, load_words};
+
+include!("../assets/valid_guesses.rs");
+include!("../assets/wordlist.rs");
+include!("../assets/words_freqs.rs");
+include!("../assets/english_words.rs");
+include!("../assets/english_guesses.rs");
+
+/// Length of the words in the bundled lists, used when a list is empty.
+pub const DEFAULT_WORD_LENGTH: usize = 5;
+
+/// The word lists a `Solver` plays with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WordLists {
+    /// Words that can be the answer.
+    pub answers: Vec<String>,
+    /// Words accepted as guesses, usually a superset of the answers.
+    pub guesses: Vec<String>,
+    /// How common each word is, used to prefer likely answers.
+    pub frequencies: HashMap<String, f64>,
+}
+
+impl WordLists {
+    /// The Italian word lists bundled with the crate, see `Language::word_lists`
+    /// for the other languages.
+    pub fn embedded() -> Self {
+        ITALIAN.word_lists()
+    }
+
+    /// Reads the lists of `language` from files (see `loader` for the formats),
+    /// the bundled ones of the language being used for the files not given.
+    /// With custom answers, the guesses default to the answers and the
+    /// frequencies to none. The answers are always accepted as guesses.
+    pub fn load(
+        language: &Language,
+        answers: Option<&Path>,
+        guesses: Option<&Path>,
+        frequencies: Option<&Path>,
+    ) -> Result<Self, SolverError> {
+        let defaults = language.word_lists();
+        let custom_answers = answers.is_some();
+        let answers = match answers {
+            Some(path) => load_words(path, language)?,
+            None => defaults.answers,
+        };
+        let mut guesses = match guesses {
+            Some(path) => load_words(path, language)?,
+            None if custom_answers => answers.clone(),
+            None => defaults.guesses,
+        };
+        let frequencies = match frequencies {
+            Some(path) => load_frequencies(path, language)?,
+            None if custom_answers => HashMap::new(),
+            None => defaults.frequencies,
+        };
+
+        let known: HashSet<String> = guesses.iter().cloned().collect();
+        let missing: Vec<String> = answers.iter().filter(|word| !known.contains(*word)).cloned().collect();
+        guesses.extend(missing);
+
+        Ok(WordLists { answers, guesses, frequencies })
+    }
+
+    /// Length of the words being played, the one of the first answer.
+    pub fn word_length(&self) -> usize {
+        self.answers
+            .first()
+            .or_else(|| self.guesses.first())
+            .map_or(DEFAULT_WORD_LENGTH, |word| word.chars().count())
+    }
+
+    /// Keeps only the words with `length` letters, so lists mixing several
+    /// lengths can be played one length at a time.
+    pub fn with_length(self, length: usize) -> Self {
+        let keep = |word: &String| word.chars().count() == length;
+        WordLists {
+            answers: self.answers.into_iter().filter(keep).collect(),
+            guesses: self.guesses.into_iter().filter(keep).collect(),
+            frequencies: self.frequencies.into_iter().filter(|(word, _)| keep(word)).collect(),
+        }
+    }
+}
+
+/// Number of edits turning `a` into `b`: inserting, removing or replacing a
+/// letter, or swapping two adjacent ones, which is a common typo.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // distances[i][j]: edits between the first i letters of a and the first j of b
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    distances[0] = (0..=b.len()).collect();
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let replace = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + replace);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
+
+impl Default for WordLists {
+    fn default() -> Self {
+        Self::embedded()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_distance_counts_each_kind_of_edit_once() {
+        assert_eq!(edit_distance("seria", "seria"), 0);
+        assert_eq!(edit_distance("seria", "serie"), 1);
+        assert_eq!(edit_distance("seria", "seri"), 1);
+        assert_eq!(edit_distance("seri", "seria"), 1);
+        assert_eq!(edit_distance("", "casa"), 4);
+    }
+
+    #[test]
+    fn edit_distance_counts_an_adjacent_swap_as_one_edit() {
+        assert_eq!(edit_distance("sreia", "seria"), 1);
+        assert_eq!(edit_distance("esira", "seria"), 2);
+        assert_eq!(edit_distance("sreai", "seria"), 2);
+    }
+}